@@ -1 +1,2 @@
 pub mod main_controller;
+pub mod cli_controller;
@@ -0,0 +1,158 @@
+use crate::model::settings::Settings;
+use crate::repository::settings_repository::SettingsRepository;
+use crate::service::catalogue_service::IngredientCatalogueService;
+use crate::service::recipe_service::RecipeService;
+use crate::service::search_service::{RecipeQuery, RecipeSortKey};
+use std::path::Path;
+
+const COMMAND__IMPORT: &str = "import";
+const COMMAND__CONVERT: &str = "convert";
+const COMMAND__SEARCH: &str = "search";
+const COMMAND__CHECK: &str = "check";
+const COMMAND__HELP: &str = "help";
+
+const USAGE: &str = "Usage :
+    menus_manager                         Launch the application
+    menus_manager import <file>...        Import recipes (.html with schema.org JSON-LD, .md, .csv)
+    menus_manager convert <recipe.txt>... Convert .txt recipes to the .toml format
+    menus_manager search <query>...       List the recipes matching the query, the most relevant first
+    menus_manager check                   Compare the veggie flag of the recipes with their ingredients
+                                          and list the ingredients missing from the catalogue
+    menus_manager help                    Show this message";
+
+/// Runs the command given on the command line and returns the exit code of the process.
+pub fn run(arguments: &[String]) -> i32 {
+    match arguments[0].as_str() {
+        COMMAND__IMPORT if arguments.len() > 1 => import(&arguments[1..]),
+        COMMAND__CONVERT if arguments.len() > 1 => convert(&arguments[1..]),
+        COMMAND__SEARCH => search(&arguments[1..]),
+        COMMAND__CHECK => check(),
+        COMMAND__HELP => {
+            println!("{USAGE}\n\nQuery syntax : {}", load_settings().language.texts().query_syntax_help);
+            0
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            1
+        }
+    }
+}
+
+/// Saved settings, the default ones if they can't be loaded.
+fn load_settings() -> Settings {
+    SettingsRepository::new().load().unwrap_or_else(|error| {
+        eprintln!("{error}");
+        Settings::default()
+    })
+}
+
+/// Service holding the recipes of the configured directory, the recipe files that can't be
+/// read being reported on the error output.
+fn load_recipe_service() -> Result<(RecipeService, usize), String> {
+    let mut recipe_service = RecipeService::new(&load_settings().recipes_directory);
+    let loading_errors = recipe_service.load_all_recipes()?;
+    for loading_error in loading_errors.iter().chain(recipe_service.recipe_stats_error.iter()) {
+        eprintln!("{loading_error}");
+    }
+    Ok((recipe_service, loading_errors.len()))
+}
+
+fn import(files_to_import: &[String]) -> i32 {
+    let mut recipe_service = match load_recipe_service() {
+        Ok((recipe_service, _)) => recipe_service,
+        Err(error) => {
+            eprintln!("{error}");
+            return 1;
+        }
+    };
+
+    let mut exit_code = 0;
+    for file_to_import in files_to_import {
+        match recipe_service.import_recipe(Path::new(file_to_import)) {
+            Ok(recipe_file) => println!("{} -> {}", file_to_import, recipe_file.display()),
+            Err(error) => {
+                eprintln!("{file_to_import} : {error}");
+                exit_code = 1;
+            }
+        }
+    }
+    exit_code
+}
+
+fn convert(recipe_files: &[String]) -> i32 {
+    let mut recipe_service = RecipeService::new(&load_settings().recipes_directory);
+
+    let mut exit_code = 0;
+    for recipe_file in recipe_files {
+        match recipe_service.convert_recipe_to_toml(Path::new(recipe_file)) {
+            Ok(toml_recipe_file) => println!("{} -> {}", recipe_file, toml_recipe_file.display()),
+            Err(error) => {
+                eprintln!("{recipe_file} : {error}");
+                exit_code = 1;
+            }
+        }
+    }
+    exit_code
+}
+
+fn search(query_words: &[String]) -> i32 {
+    let recipe_service = match load_recipe_service() {
+        Ok((recipe_service, _)) => recipe_service,
+        Err(error) => {
+            eprintln!("{error}");
+            return 1;
+        }
+    };
+
+    let language = load_settings().language;
+    let query = RecipeQuery::parse(&query_words.join(" "));
+    for recipe_name in recipe_service.search_recipes(&query, RecipeSortKey::Alphabetical) {
+        let metadata_summary = recipe_service
+            .find_recipe_by_name(&recipe_name)
+            .map(|recipe| recipe.metadata.summary(language))
+            .unwrap_or_default();
+        match metadata_summary.is_empty() {
+            true => println!("{recipe_name}"),
+            false => println!("{recipe_name}  ({metadata_summary})"),
+        }
+    }
+    0
+}
+
+fn check() -> i32 {
+    let (recipe_service, nbr_unreadable_recipes) = match load_recipe_service() {
+        Ok(loaded_recipes) => loaded_recipes,
+        Err(error) => {
+            eprintln!("{error}");
+            return 1;
+        }
+    };
+    let catalogue_service = IngredientCatalogueService::new();
+    if let Some(error) = &catalogue_service.catalogue_error {
+        eprintln!("{error}");
+    }
+    let language = load_settings().language;
+
+    let mut unknown_ingredients: Vec<String> = Vec::new();
+    for recipe_name in recipe_service.recipe_repository.list_all_recipes_names() {
+        let recipe = recipe_service.find_recipe_by_name(&recipe_name).unwrap();
+        let diet_profile = catalogue_service.diet_profile(&recipe);
+        if let Some(inconsistency) = diet_profile.veggie_inconsistency(recipe.is_veggie, language) {
+            println!("{recipe_name} : {inconsistency}");
+        }
+        unknown_ingredients.extend(diet_profile.unknown_ingredients);
+    }
+
+    unknown_ingredients.sort();
+    unknown_ingredients.dedup();
+    if !unknown_ingredients.is_empty() {
+        println!("\n{}", language.texts().missing_from_catalogue);
+        for unknown_ingredient in unknown_ingredients {
+            println!("    {unknown_ingredient}");
+        }
+    }
+    match nbr_unreadable_recipes == 0 && catalogue_service.catalogue_error.is_none() {
+        true => 0,
+        false => 1,
+    }
+}
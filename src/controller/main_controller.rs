@@ -1,128 +1,612 @@
-use crate::model::ingredient::Ingredient;
+use crate::model::calendar_date::CalendarDate;
+use crate::model::dashboard::WeekDashboard;
+use crate::model::diet::DietaryRestriction;
+use crate::model::history::History;
+use crate::model::language::Language;
+use crate::model::ingredient::{Ingredient, WHOLE_INGREDIENT, parse_quantity};
 use crate::model::menu::Menu;
+use crate::model::person_count::PersonCount;
 use crate::model::recipe::Recipe;
-use crate::model::weekday::{
-    FRIDAY, MONDAY, SATURDAY, SUNDAY, THURSDAY, TUESDAY, WEDNESDAY, WeekDay,
-};
+use crate::model::recipe_stats::MAX_RATING_STARS;
+use crate::model::recipe_slot::{Day, Meal, RecipeSlot};
+use crate::model::settings::{DEFAULT_RECIPES_DIRECTORY, Settings};
+use crate::model::shopping_list::{ShoppingItemKey, ShoppingListEdits, ShoppingListLine};
+use crate::model::theme::ThemeMode;
+use crate::model::weekday::WeekDay;
+use crate::repository::settings_repository::SettingsRepository;
+use crate::service::catalogue_service::IngredientCatalogueService;
+use crate::service::dashboard_service::week_dashboard;
 use crate::service::excel_service::{read_from_excel_menu, write_excel_menu};
+use crate::service::household_service::HouseholdService;
+use crate::service::import_service::IMPORTABLE_EXTENSIONS;
 use crate::service::recipe_service::RecipeService;
+use crate::service::search_service::RecipeSortKey;
+use iced::event::{self, Event, Status};
+use iced::keyboard::{self, Key, Modifiers, key::Named};
+use iced::widget::text_input;
+use iced::{Subscription, Task};
+use native_dialog::{DialogBuilder, MessageLevel};
 use std::collections::HashMap;
 
+pub const RECIPE_SEARCH_INPUT: &str = "recipe_search";
+
 pub struct MainController {
     pub recipe_service: RecipeService,
+    pub catalogue_service: IngredientCatalogueService,
+    pub household_service: HouseholdService,
     pub selected_recipes: HashMap<RecipeSlot, Recipe>,
     pub slot_currently_in_edition: Option<RecipeSlot>,
     pub filters_on_recipes_slots: HashMap<RecipeSlot, String>,
     pub current_view: View,
     pub week_days: Vec<WeekDay>,
     pub slots_filtering_veggie_recipes: Vec<RecipeSlot>,
+    pub recipe_sort_key: RecipeSortKey,
+    pub slots_filtering_favourite_recipes: Vec<RecipeSlot>,
+    pub recipe_in_rating: Option<String>,
+    pub rating_stars: u8,
+    pub rating_note: String,
+    pub household_restrictions: Vec<DietaryRestriction>,
+    pub absent_members_by_slot: HashMap<RecipeSlot, Vec<String>>,
+    pub allow_half_portions: bool,
+    // Text typed in the person count input of a slot, kept while it isn't a valid count
+    pub nbr_persons_inputs: HashMap<RecipeSlot, String>,
+    pub slots_filtering_seasonal_recipes: Vec<RecipeSlot>,
+    // Monday of the planned week
+    pub week_start: CalendarDate,
+    pub week_start_input: String,
+    pub clipboard: Option<SlotClipboard>,
+    pub plan_history: History<PlanSnapshot>,
+    // Slot of the grid selected with the arrow keys
+    pub focused_slot: RecipeSlot,
+    // Position, in the selection view, of the recipe chosen with the arrow keys
+    pub highlighted_recipe: usize,
+    pub settings_repository: SettingsRepository,
+    pub settings: Settings,
+    // Settings being edited in the settings view, saved only once valid
+    pub settings_in_edition: Settings,
+    pub default_nbr_persons_input: String,
+    pub settings_error: Option<String>,
+    // Why the saved settings couldn't be used as they are at startup. Until the settings are saved
+    // from the settings view, the file is left untouched so that nothing is lost.
+    pub settings_warning: Option<String>,
+    pub shopping_list_edits: ShoppingListEdits,
+    // Text typed in the quantity input of a shopping list line, kept while it isn't a valid quantity
+    pub shopping_quantity_inputs: HashMap<ShoppingItemKey, String>,
+    pub manual_item_name: String,
+    pub manual_item_quantity: String,
+    pub manual_item_unit: String,
+    pub manual_item_error: Option<String>,
+    // Recipe files skipped because they couldn't be read
+    pub recipe_loading_errors: Vec<String>,
+    // Last failure to load or save the statistics of the recipes
+    pub recipe_stats_error: Option<String>,
+    // Computed when the plan or the shopping list changes, see `refresh_leftover_suggestions`
+    pub leftover_suggestions: Vec<(String, Vec<String>)>,
 }
 
 impl Default for MainController {
     fn default() -> Self {
-        let mut recipe_service = RecipeService::new();
-        recipe_service.load_all_recipes();
-        let week_days: Vec<WeekDay> = Vec::from([
-            WeekDay::new(
-                String::from(MONDAY),
-                0,
-                RecipeSlot::MondayNoon,
-                RecipeSlot::MondayEvening,
-            ),
-            WeekDay::new(
-                String::from(TUESDAY),
-                1,
-                RecipeSlot::TuesdayNoon,
-                RecipeSlot::TuesdayEvening,
-            ),
-            WeekDay::new(
-                String::from(WEDNESDAY),
-                2,
-                RecipeSlot::WednesdayNoon,
-                RecipeSlot::WednesdayEvening,
-            ),
-            WeekDay::new(
-                String::from(THURSDAY),
-                3,
-                RecipeSlot::ThursdayNoon,
-                RecipeSlot::ThursdayEvening,
-            ),
-            WeekDay::new(
-                String::from(FRIDAY),
-                4,
-                RecipeSlot::FridayNoon,
-                RecipeSlot::FridayEvening,
-            ),
-            WeekDay::new(
-                String::from(SATURDAY),
-                5,
-                RecipeSlot::SaturdayNoon,
-                RecipeSlot::SaturdayEvening,
-            ),
-            WeekDay::new(
-                String::from(SUNDAY),
-                6,
-                RecipeSlot::SaturdayNoon,
-                RecipeSlot::SundayEvening,
-            ),
-        ]);
+        let settings_repository = SettingsRepository::new();
+        let (mut settings, mut settings_warning) = match settings_repository.load() {
+            Ok(settings) => (settings, None),
+            Err(error) => {
+                let settings = Settings::default();
+                (settings.clone(), Some(format!("{}{error}", settings.language.texts().settings_not_loaded)))
+            }
+        };
+        let mut recipe_service = RecipeService::new(&settings.recipes_directory);
+        let recipe_loading_errors = match recipe_service.load_all_recipes() {
+            Ok(recipe_loading_errors) => recipe_loading_errors,
+            // A directory moved or unmounted since : the default one is used for this session
+            Err(error) => {
+                settings_warning = Some(format!("{}{error}", settings.language.texts().default_recipes_directory_used));
+                settings.recipes_directory = DEFAULT_RECIPES_DIRECTORY.to_string();
+                recipe_service = RecipeService::new(&settings.recipes_directory);
+                recipe_service.load_all_recipes().unwrap_or_else(|error| vec![error])
+            }
+        };
+        let recipe_stats_error = recipe_service.recipe_stats_error.clone();
+        let catalogue_service = IngredientCatalogueService::new();
+        if let Some(error) = &catalogue_service.catalogue_error {
+            alert_loading_error(settings.language.texts().catalogue_not_loaded, error, settings.language);
+        }
+        let household_service = HouseholdService::new();
+        if let Some(error) = &household_service.household_error {
+            alert_loading_error(settings.language.texts().household_not_loaded, error, settings.language);
+        }
+        let week_days: Vec<WeekDay> = Day::ALL.into_iter().map(WeekDay::new).collect();
+        let week_start = CalendarDate::today().monday_of_week();
 
         MainController {
             recipe_service: recipe_service,
+            catalogue_service,
+            household_service,
             selected_recipes: HashMap::new(),
             filters_on_recipes_slots: HashMap::new(),
             slot_currently_in_edition: None,
             current_view: View::Main,
             week_days,
             slots_filtering_veggie_recipes: Vec::new(),
+            recipe_sort_key: RecipeSortKey::default(),
+            slots_filtering_favourite_recipes: Vec::new(),
+            recipe_in_rating: None,
+            rating_stars: MAX_RATING_STARS,
+            rating_note: String::new(),
+            household_restrictions: Vec::new(),
+            absent_members_by_slot: HashMap::new(),
+            allow_half_portions: false,
+            nbr_persons_inputs: HashMap::new(),
+            slots_filtering_seasonal_recipes: Vec::new(),
+            week_start,
+            week_start_input: week_start.to_string(),
+            clipboard: None,
+            plan_history: History::default(),
+            focused_slot: Day::Monday.slot(Meal::Noon),
+            highlighted_recipe: 0,
+            settings_repository,
+            settings_in_edition: settings.clone(),
+            default_nbr_persons_input: String::new(),
+            settings,
+            settings_error: None,
+            settings_warning,
+            shopping_list_edits: ShoppingListEdits::default(),
+            shopping_quantity_inputs: HashMap::new(),
+            manual_item_name: String::new(),
+            manual_item_quantity: String::new(),
+            manual_item_unit: String::new(),
+            manual_item_error: None,
+            recipe_loading_errors,
+            recipe_stats_error,
+            leftover_suggestions: Vec::new(),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
-pub enum RecipeSlot {
-    MondayNoon,
-    MondayEvening,
-    TuesdayNoon,
-    TuesdayEvening,
-    WednesdayNoon,
-    WednesdayEvening,
-    ThursdayNoon,
-    ThursdayEvening,
-    FridayNoon,
-    FridayEvening,
-    SaturdayNoon,
-    SaturdayEvening,
-    SundayNoon,
-    SundayEvening,
-}
-
 #[derive(Debug, Clone)]
 pub enum Message {
     SelectedRecipeSlot(RecipeSlot),
     ReturnButtonPressed,
     FilteredSlotRecipe(String),
     FilteringVeggieRecipes(bool),
+    SelectedRecipeSortKey(RecipeSortKey),
+    FilteringFavouriteRecipes(bool),
+    FilteringSeasonalRecipes(bool),
+    ToggledFavouriteRecipe(String),
+    RateRecipeButtonPressed(String),
+    SelectedRatingStars(u8),
+    EditedRatingNote(String),
+    SavedRecipeRating,
+    ToggledHouseholdRestriction(DietaryRestriction, bool),
+    ToggledMemberAttendance(RecipeSlot, String, bool),
     SelectedRecipe(RecipeSlot, Option<String>),
     GenerateRecipeDocument,
-    IncrementedNbrPersonsOfRecipe(RecipeSlot, u8),
-    DecrementedNbrPersonsOfRecipe(RecipeSlot, u8),
+    IncrementedNbrPersonsOfRecipe(RecipeSlot, PersonCount),
+    DecrementedNbrPersonsOfRecipe(RecipeSlot, PersonCount),
+    EditedNbrPersonsOfRecipe(RecipeSlot, String),
+    ResetNbrPersonsOfRecipe(RecipeSlot),
+    ToggledHalfPortions(bool),
+    EditedWeekStart(String),
+    CutRecipe(RecipeSlot),
+    CopiedRecipe(RecipeSlot),
+    PastedRecipe(RecipeSlot),
+    CancelledClipboard,
+    Undo,
+    Redo,
+    KeyPressed(Key, Modifiers),
+    ConfirmedHighlightedRecipe,
+    SelectedLanguage(Language),
+    OpenedSettings,
+    EditedSetting(SettingField, String),
+    SelectedSettingsLanguage(Language),
+    SelectedTheme(ThemeMode),
+    RestoredDefaultSettings,
+    SavedSettings,
+    OpenedShoppingList,
+    ToggledShoppingItem(ShoppingItemKey, bool),
+    EditedShoppingItemQuantity(ShoppingItemKey, String),
+    EditedManualItem(ManualItemField, String),
+    AddedManualItem,
+    RemovedManualItem(ShoppingItemKey),
+    ResetShoppingList,
     ImportExcelFile,
+    ImportRecipeFile,
+}
+
+/// Recipe waiting to be pasted on another slot : a cut recipe leaves its slot when pasted,
+/// taking the place of the recipe already there if any, a copied one can be pasted many times.
+/// A cut is forgotten once its slot no longer holds the recipe cut, see `drop_stale_clipboard`.
+#[derive(Debug, Clone)]
+pub enum SlotClipboard {
+    Cut(RecipeSlot, String),
+    Copied(Recipe),
+}
+
+/// What undo and redo bring back : the recipes planned, with their number of persons,
+/// and who eats at each meal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanSnapshot {
+    selected_recipes: HashMap<RecipeSlot, Recipe>,
+    absent_members_by_slot: HashMap<RecipeSlot, Vec<String>>,
+}
+
+/// Settings typed in a text input of the settings view.
+#[derive(Debug, Clone, Copy)]
+pub enum SettingField {
+    RecipesDirectory,
+    OutputFile,
+    HeaderColour,
+    DefaultNbrPersons,
+    NoonTime,
+    EveningTime,
+    AccentColour,
+}
+
+/// Inputs of the item added by hand to the shopping list.
+#[derive(Debug, Clone, Copy)]
+pub enum ManualItemField {
+    Name,
+    Quantity,
+    Unit,
 }
 
 pub enum View {
     Main,
     RecipeSelection,
+    RecipeRating,
+    Settings,
+    ShoppingList,
 }
 
 impl MainController {
-    pub fn update(&mut self, message: Message) {
+    pub fn nbr_persons_step(&self) -> PersonCount {
+        match self.allow_half_portions {
+            true => PersonCount::HALF,
+            false => PersonCount::ONE,
+        }
+    }
+
+    pub fn absent_members_of(&self, recipe_slot: RecipeSlot) -> &[String] {
+        self.absent_members_by_slot.get(&recipe_slot).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn date_of(&self, day: Day) -> CalendarDate {
+        self.week_start.add_days(day.offset())
+    }
+
+    /// Fruits and vegetables of the recipe out of season on the date of the slot.
+    pub fn out_of_season_ingredients(&self, recipe: &Recipe, recipe_slot: RecipeSlot) -> Vec<String> {
+        self.catalogue_service
+            .out_of_season_ingredients(recipe, self.date_of(recipe_slot.day).month)
+    }
+
+    /// Moves the recipe of a slot to another one, swapping them if both are planned. Who eats at
+    /// a meal doesn't follow the recipe : with a household, the number of persons of the moved
+    /// recipes is that of the members present at their new slot, otherwise they keep their own.
+    pub fn move_recipe(&mut self, from_slot: RecipeSlot, to_slot: RecipeSlot) {
+        if from_slot == to_slot {
+            return;
+        }
+        let moved_recipe = self.selected_recipes.remove(&from_slot);
+        let replaced_recipe = self.selected_recipes.remove(&to_slot);
+        if let Some(moved_recipe) = moved_recipe {
+            self.selected_recipes.insert(to_slot, moved_recipe);
+        }
+        if let Some(replaced_recipe) = replaced_recipe {
+            self.selected_recipes.insert(from_slot, replaced_recipe);
+        }
+        if self.household_service.has_members() {
+            for recipe_slot in [from_slot, to_slot] {
+                let nbr_persons =
+                    self.household_service.nbr_persons(self.absent_members_of(recipe_slot), self.allow_half_portions);
+                if let Some(recipe) = self.selected_recipes.get_mut(&recipe_slot) {
+                    recipe.configured_nbr_persons = nbr_persons;
+                }
+            }
+        }
+        self.nbr_persons_inputs.remove(&from_slot);
+        self.nbr_persons_inputs.remove(&to_slot);
+    }
+
+    /// Forgets a cut whose slot was emptied or given another recipe since (undo, import, ...), so
+    /// that pasting doesn't move whatever is there now.
+    fn drop_stale_clipboard(&mut self) {
+        if let Some(SlotClipboard::Cut(cut_slot, recipe_name)) = &self.clipboard
+            && self.selected_recipes.get(cut_slot).is_none_or(|recipe| recipe.name != *recipe_name)
+        {
+            self.clipboard = None;
+        }
+    }
+
+    /// Ingredients of all the selected recipes added up, with the edits of the shopping list view.
+    pub fn shopping_list(&self) -> Vec<ShoppingListLine> {
+        self.recipe_service.shopping_list(&self.selected_recipes, &self.shopping_list_edits)
+    }
+
+    /// What is left to buy for the week, the items ticked off being already at home.
+    pub fn week_shopping_list(&self) -> Vec<Ingredient> {
+        self.shopping_list()
+            .into_iter()
+            .filter(|line| !line.is_ticked)
+            .map(|line| line.ingredient)
+            .collect()
+    }
+
+    fn add_manual_item(&mut self) -> Result<(), String> {
+        let texts = self.settings.language.texts();
+        let name = self.manual_item_name.trim();
+        if name.is_empty() {
+            return Err(texts.item_name_required.to_string());
+        }
+        let quantity = match self.manual_item_quantity.trim() {
+            "" => 1.0,
+            quantity => parse_quantity(quantity)
+                .filter(|quantity| *quantity > 0.0)
+                .ok_or_else(|| format!("« {quantity} » {}", texts.not_a_quantity))?,
+        };
+        let unit = match self.manual_item_unit.trim() {
+            "" => WHOLE_INGREDIENT.to_string(),
+            unit => unit.to_string(),
+        };
+        self.shopping_list_edits.manual_items.push(Ingredient { name: name.to_string(), unit, quantity });
+        self.manual_item_name.clear();
+        self.manual_item_quantity.clear();
+        self.manual_item_unit.clear();
+        Ok(())
+    }
+
+    pub fn week_dashboard(&self) -> WeekDashboard {
+        week_dashboard(&self.selected_recipes, &self.week_shopping_list(), |recipe_name| {
+            self.recipe_service.recipe_repository.get_recipe(recipe_name).is_some()
+        })
+    }
+
+    /// Leftovers of the packages bought for the shopping list, along with a few recipes that
+    /// could use them up on the slots still free. Looking for those recipes goes through the
+    /// whole catalogue, hence this is only done when the plan or the shopping list changes.
+    fn refresh_leftover_suggestions(&mut self) {
+        let all_ingredients = self.week_shopping_list();
+        self.leftover_suggestions = self.compute_leftover_suggestions(&all_ingredients);
+    }
+
+    fn compute_leftover_suggestions(&self, all_ingredients: &[Ingredient]) -> Vec<(String, Vec<String>)> {
+        let has_free_slot = self
+            .week_days
+            .iter()
+            .flat_map(|week_day| [week_day.noon_recipe_slot, week_day.evening_recipe_slot])
+            .any(|recipe_slot| !self.selected_recipes.contains_key(&recipe_slot));
+        let selected_names: Vec<&String> = self.selected_recipes.values().map(|recipe| &recipe.name).collect();
+        let candidate_recipes: Vec<Recipe> = match has_free_slot {
+            true => self
+                .recipe_service
+                .recipe_repository
+                .list_all_recipes_names()
+                .iter()
+                .filter(|name| !selected_names.contains(name))
+                .filter_map(|name| self.recipe_service.recipe_repository.get_recipe(name))
+                .collect(),
+            false => Vec::new(),
+        };
+
+        all_ingredients
+            .iter()
+            .filter_map(|ingredient| {
+                let package_plan = self.catalogue_service.package_plan(ingredient)?;
+                package_plan.has_leftover().then(|| {
+                    (
+                        format!("{} : {}", ingredient.name, package_plan.leftover_amount()),
+                        self.catalogue_service.recipes_using(ingredient, candidate_recipes.iter()),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Recipes offered for the slot in edition, in the order they are listed.
+    pub fn listed_recipe_names(&self) -> Vec<String> {
+        let recipe_slot = self.slot_currently_in_edition.unwrap();
+        let filter = self.filters_on_recipes_slots.get(&recipe_slot).cloned().unwrap_or_default();
+        let only_seasonal_recipes = self.slots_filtering_seasonal_recipes.contains(&recipe_slot);
+        self.recipe_service
+            .list_recipes(
+                filter,
+                self.slots_filtering_veggie_recipes.contains(&recipe_slot),
+                self.slots_filtering_favourite_recipes.contains(&recipe_slot),
+                self.recipe_sort_key,
+            )
+            .into_iter()
+            .filter(|recipe_name| {
+                let Some(recipe) = self.recipe_service.find_recipe_by_name(recipe_name) else {
+                    return false;
+                };
+                let diet_profile = self.catalogue_service.diet_profile(&recipe);
+                self.household_restrictions
+                    .iter()
+                    .all(|restriction| diet_profile.satisfies(*restriction))
+                    && (!only_seasonal_recipes || self.out_of_season_ingredients(&recipe, recipe_slot).is_empty())
+            })
+            .collect()
+    }
+
+    fn record_recipe_planned(&mut self, recipe_name: &str) {
+        if let Err(error) = self.recipe_service.record_recipe_planned(recipe_name) {
+            self.recipe_stats_error = Some(error);
+        }
+    }
+
+    /// Checks and saves the settings in edition, reloading the recipes if they moved.
+    /// Saving them also replaces a settings file that couldn't be loaded at startup.
+    fn save_settings(&mut self) -> Result<(), String> {
+        let texts = self.settings.language.texts();
+        let mut settings = self.settings_in_edition.clone();
+        settings.default_nbr_persons = match self.default_nbr_persons_input.trim() {
+            "" => None,
+            input => Some(
+                input
+                    .parse::<u8>()
+                    .map_err(|_| format!("« {input} » {}", texts.not_a_nbr_persons))?,
+            ),
+        };
+        settings.validate(self.settings.language)?;
+        if !std::path::Path::new(&settings.recipes_directory).is_dir() {
+            return Err(format!("{}{}", texts.missing_directory, settings.recipes_directory));
+        }
+        check_output_directory(&settings, self.settings.language)?;
+        self.settings_repository.save(&settings)?;
+        self.settings_warning = None;
+
+        if settings.recipes_directory != self.settings.recipes_directory {
+            let mut recipe_service = RecipeService::new(&settings.recipes_directory);
+            self.recipe_loading_errors = recipe_service.load_all_recipes()?;
+            if recipe_service.recipe_stats_error.is_some() {
+                self.recipe_stats_error = recipe_service.recipe_stats_error.clone();
+            }
+            self.recipe_service = recipe_service;
+            self.refresh_leftover_suggestions();
+        }
+        self.settings = settings;
+        Ok(())
+    }
+
+    pub fn plan_snapshot(&self) -> PlanSnapshot {
+        PlanSnapshot {
+            selected_recipes: self.selected_recipes.clone(),
+            absent_members_by_slot: self.absent_members_by_slot.clone(),
+        }
+    }
+
+    fn restore_plan(&mut self, plan_snapshot: PlanSnapshot) {
+        self.selected_recipes = plan_snapshot.selected_recipes;
+        self.absent_members_by_slot = plan_snapshot.absent_members_by_slot;
+        self.nbr_persons_inputs.clear();
+    }
+
+    /// Applies the message, recording the plan it changes so that it can be undone.
+    pub fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Undo => {
+                if let Some(previous_plan) = self.plan_history.undo(self.plan_snapshot()) {
+                    self.restore_plan(previous_plan);
+                    self.drop_stale_clipboard();
+                    self.refresh_leftover_suggestions();
+                }
+            }
+            Message::Redo => {
+                if let Some(next_plan) = self.plan_history.redo(self.plan_snapshot()) {
+                    self.restore_plan(next_plan);
+                    self.drop_stale_clipboard();
+                    self.refresh_leftover_suggestions();
+                }
+            }
+            Message::KeyPressed(key, modifiers) => return self.handle_key_press(key, modifiers),
+            message => {
+                let opens_recipe_selection = matches!(message, Message::SelectedRecipeSlot(_));
+                let plan_before = self.plan_snapshot();
+                let shopping_list_edits_before = self.shopping_list_edits.clone();
+                self.apply(message);
+                let has_plan_changed = self.plan_snapshot() != plan_before;
+                if has_plan_changed {
+                    self.plan_history.record(plan_before);
+                    self.drop_stale_clipboard();
+                }
+                if has_plan_changed || self.shopping_list_edits != shopping_list_edits_before {
+                    self.refresh_leftover_suggestions();
+                }
+                if opens_recipe_selection {
+                    return text_input::focus(text_input::Id::new(RECIPE_SEARCH_INPUT));
+                }
+            }
+        }
+        Task::none()
+    }
+
+    /// Keys not already used by a widget, Escape always leaving the current view.
+    pub fn subscription(&self) -> Subscription<Message> {
+        event::listen_with(|event, status, _window| match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                if status == Status::Ignored || key == Key::Named(Named::Escape) =>
+            {
+                Some(Message::KeyPressed(key, modifiers))
+            }
+            _ => None,
+        })
+    }
+
+    /// Keyboard shortcuts :
+    /// - Ctrl+Z undoes, Ctrl+Y or Ctrl+Shift+Z redoes, Ctrl+G generates the menu, Ctrl+O imports one
+    /// - in the grid, the arrows move across the slots and Enter opens the recipe selection
+    /// - in the recipe selection, typing searches, the arrows pick a recipe and Enter confirms it
+    /// - Escape goes back to the grid
+    fn handle_key_press(&mut self, key: Key, modifiers: Modifiers) -> Task<Message> {
+        let shortcut = match key.as_ref() {
+            Key::Character("z" | "Z") if modifiers.command() && modifiers.shift() => Some(Message::Redo),
+            Key::Character("z") if modifiers.command() => Some(Message::Undo),
+            Key::Character("y" | "Y") if modifiers.command() => Some(Message::Redo),
+            Key::Character("g" | "G") if modifiers.command() => Some(Message::GenerateRecipeDocument),
+            Key::Character("o" | "O") if modifiers.command() => Some(Message::ImportExcelFile),
+            Key::Named(Named::Escape) if !matches!(self.current_view, View::Main) => {
+                Some(Message::ReturnButtonPressed)
+            }
+            _ => None,
+        };
+        if let Some(message) = shortcut {
+            return self.update(message);
+        }
+
+        match self.current_view {
+            View::Main => match key.as_ref() {
+                Key::Named(Named::ArrowLeft) => self.focused_slot = self.neighbour_slot(-1, 0),
+                Key::Named(Named::ArrowRight) => self.focused_slot = self.neighbour_slot(1, 0),
+                Key::Named(Named::ArrowUp) => self.focused_slot = self.neighbour_slot(0, -1),
+                Key::Named(Named::ArrowDown) => self.focused_slot = self.neighbour_slot(0, 1),
+                Key::Named(Named::Enter) => return self.update(Message::SelectedRecipeSlot(self.focused_slot)),
+                _ => {}
+            },
+            View::RecipeSelection => match key.as_ref() {
+                Key::Named(Named::ArrowUp) => self.highlighted_recipe = self.highlighted_recipe.saturating_sub(1),
+                Key::Named(Named::ArrowDown) => {
+                    let nbr_listed_recipes = self.listed_recipe_names().len();
+                    self.highlighted_recipe = (self.highlighted_recipe + 1).min(nbr_listed_recipes.saturating_sub(1));
+                }
+                Key::Named(Named::Enter) => return self.update(Message::ConfirmedHighlightedRecipe),
+                Key::Character(typed_text) if !modifiers.command() => {
+                    let recipe_slot = self.slot_currently_in_edition.unwrap();
+                    let mut filter = self.filters_on_recipes_slots.get(&recipe_slot).cloned().unwrap_or_default();
+                    filter.push_str(typed_text);
+                    self.apply(Message::FilteredSlotRecipe(filter));
+                    return Task::batch([
+                        text_input::focus(text_input::Id::new(RECIPE_SEARCH_INPUT)),
+                        text_input::move_cursor_to_end(text_input::Id::new(RECIPE_SEARCH_INPUT)),
+                    ]);
+                }
+                _ => {}
+            },
+            View::RecipeRating | View::Settings | View::ShoppingList => {}
+        }
+        Task::none()
+    }
+
+    // Slot `day_shift` days and `meal_shift` meals away from the focused one, staying in the grid
+    fn neighbour_slot(&self, day_shift: i64, meal_shift: i64) -> RecipeSlot {
+        let day_position = (self.focused_slot.day.offset() + day_shift).clamp(0, Day::ALL.len() as i64 - 1);
+        let meal_position = Meal::ALL.iter().position(|meal| *meal == self.focused_slot.meal).unwrap() as i64;
+        let meal_position = (meal_position + meal_shift).clamp(0, Meal::ALL.len() as i64 - 1);
+        Day::ALL[day_position as usize].slot(Meal::ALL[meal_position as usize])
+    }
+
+    fn apply(&mut self, message: Message) {
         match message {
             Message::SelectedRecipeSlot(slot) => {
                 self.slot_currently_in_edition = Some(slot);
+                self.focused_slot = slot;
+                self.highlighted_recipe = 0;
                 self.current_view = View::RecipeSelection;
             }
-            Message::ReturnButtonPressed => self.current_view = View::Main,
+            Message::ReturnButtonPressed => {
+                self.recipe_in_rating = None;
+                // The settings in edition are dropped, and the errors about them
+                self.settings_error = None;
+                self.current_view = View::Main;
+            }
             Message::FilteringVeggieRecipes(is_filtering) => {
                 if is_filtering {
                     self.slots_filtering_veggie_recipes
@@ -137,17 +621,192 @@ impl MainController {
                     }
                 }
             }
+            Message::SelectedRecipeSortKey(sort_key) => self.recipe_sort_key = sort_key,
+            Message::FilteringFavouriteRecipes(is_filtering) => {
+                let slot = self.slot_currently_in_edition.unwrap();
+                if is_filtering {
+                    self.slots_filtering_favourite_recipes.push(slot);
+                } else {
+                    self.slots_filtering_favourite_recipes.retain(|value| *value != slot);
+                }
+            }
+            Message::FilteringSeasonalRecipes(is_filtering) => {
+                let slot = self.slot_currently_in_edition.unwrap();
+                if is_filtering {
+                    self.slots_filtering_seasonal_recipes.push(slot);
+                } else {
+                    self.slots_filtering_seasonal_recipes.retain(|value| *value != slot);
+                }
+            }
+            Message::EditedWeekStart(input) => {
+                if let Ok(date) = CalendarDate::parse(&input, self.settings.language) {
+                    self.week_start = date.monday_of_week();
+                }
+                self.week_start_input = input;
+            }
+            Message::CutRecipe(recipe_slot) => {
+                self.clipboard = self
+                    .selected_recipes
+                    .get(&recipe_slot)
+                    .map(|recipe| SlotClipboard::Cut(recipe_slot, recipe.name.clone()));
+            }
+            Message::CopiedRecipe(recipe_slot) => {
+                self.clipboard = self.selected_recipes.get(&recipe_slot).cloned().map(SlotClipboard::Copied);
+            }
+            Message::PastedRecipe(recipe_slot) => match self.clipboard.take() {
+                Some(SlotClipboard::Cut(from_slot, _)) => self.move_recipe(from_slot, recipe_slot),
+                Some(SlotClipboard::Copied(recipe)) => {
+                    self.record_recipe_planned(&recipe.name);
+                    self.selected_recipes.insert(recipe_slot, recipe.clone());
+                    self.nbr_persons_inputs.remove(&recipe_slot);
+                    // A copied recipe stays available for the other repeated meals
+                    self.clipboard = Some(SlotClipboard::Copied(recipe));
+                }
+                None => {}
+            },
+            Message::SelectedLanguage(language) => {
+                self.settings.language = language;
+                self.settings_in_edition.language = language;
+                if self.settings_warning.is_none()
+                    && let Err(error) = self.settings_repository.save(&self.settings)
+                {
+                    self.settings_error = Some(error);
+                }
+            }
+            Message::OpenedSettings => {
+                self.settings_in_edition = self.settings.clone();
+                self.default_nbr_persons_input =
+                    self.settings.default_nbr_persons.map(|nbr_persons| nbr_persons.to_string()).unwrap_or_default();
+                self.settings_error = None;
+                self.current_view = View::Settings;
+            }
+            Message::EditedSetting(setting_field, input) => {
+                let settings = &mut self.settings_in_edition;
+                match setting_field {
+                    SettingField::RecipesDirectory => settings.recipes_directory = input,
+                    SettingField::OutputFile => settings.output_file = input,
+                    SettingField::HeaderColour => settings.header_colour = input,
+                    SettingField::DefaultNbrPersons => self.default_nbr_persons_input = input,
+                    SettingField::NoonTime => settings.noon_time = input,
+                    SettingField::EveningTime => settings.evening_time = input,
+                    SettingField::AccentColour => settings.accent_colour = input,
+                }
+            }
+            Message::SelectedSettingsLanguage(language) => self.settings_in_edition.language = language,
+            Message::SelectedTheme(theme_mode) => self.settings_in_edition.theme = theme_mode,
+            Message::RestoredDefaultSettings => {
+                self.settings_in_edition = Settings::default();
+                self.default_nbr_persons_input = String::new();
+            }
+            Message::OpenedShoppingList => {
+                self.manual_item_error = None;
+                self.current_view = View::ShoppingList;
+            }
+            Message::ToggledShoppingItem(key, is_ticked) => {
+                match is_ticked {
+                    true => self.shopping_list_edits.ticked_items.insert(key),
+                    false => self.shopping_list_edits.ticked_items.remove(&key),
+                };
+            }
+            Message::EditedShoppingItemQuantity(key, quantity_input) => {
+                if let Some(quantity) = parse_quantity(&quantity_input) {
+                    self.shopping_list_edits.edited_quantities.insert(key.clone(), quantity);
+                }
+                self.shopping_quantity_inputs.insert(key, quantity_input);
+            }
+            Message::EditedManualItem(manual_item_field, input) => match manual_item_field {
+                ManualItemField::Name => self.manual_item_name = input,
+                ManualItemField::Quantity => self.manual_item_quantity = input,
+                ManualItemField::Unit => self.manual_item_unit = input,
+            },
+            Message::AddedManualItem => self.manual_item_error = self.add_manual_item().err(),
+            Message::RemovedManualItem(key) => {
+                self.shopping_list_edits.manual_items.retain(|manual_item| ShoppingItemKey::of(manual_item) != key);
+                self.shopping_list_edits.ticked_items.remove(&key);
+                self.shopping_list_edits.edited_quantities.remove(&key);
+                self.shopping_quantity_inputs.remove(&key);
+            }
+            Message::ResetShoppingList => {
+                self.shopping_list_edits = ShoppingListEdits::default();
+                self.shopping_quantity_inputs.clear();
+            }
+            Message::SavedSettings => match self.save_settings() {
+                Ok(()) => {
+                    self.settings_error = None;
+                    self.current_view = View::Main;
+                }
+                Err(error) => self.settings_error = Some(error),
+            },
+            Message::CancelledClipboard => {
+                self.clipboard = None;
+            }
+            Message::ToggledFavouriteRecipe(recipe_name) => {
+                if let Err(error) = self.recipe_service.toggle_favourite_recipe(&recipe_name) {
+                    self.recipe_stats_error = Some(error);
+                }
+            }
+            Message::RateRecipeButtonPressed(recipe_name) => {
+                let last_rating = self
+                    .recipe_service
+                    .recipe_stats_repository
+                    .get_stats(&recipe_name)
+                    .last_rating()
+                    .cloned();
+                self.rating_stars = last_rating.as_ref().map_or(MAX_RATING_STARS, |rating| rating.stars);
+                self.rating_note = String::new();
+                self.recipe_in_rating = Some(recipe_name);
+                self.current_view = View::RecipeRating;
+            }
+            Message::ToggledHouseholdRestriction(restriction, is_restricted) => {
+                self.household_restrictions.retain(|value| *value != restriction);
+                if is_restricted {
+                    self.household_restrictions.push(restriction);
+                }
+            }
+            Message::ToggledMemberAttendance(recipe_slot, member_name, is_present) => {
+                let absent_members = self.absent_members_by_slot.entry(recipe_slot).or_default();
+                absent_members.retain(|value| *value != member_name);
+                if !is_present {
+                    absent_members.push(member_name);
+                }
+                let nbr_persons = self.household_service.nbr_persons(absent_members, self.allow_half_portions);
+                if let Some(recipe) = self.selected_recipes.get_mut(&recipe_slot) {
+                    recipe.configured_nbr_persons = nbr_persons;
+                }
+                self.nbr_persons_inputs.remove(&recipe_slot);
+            }
+            Message::SelectedRatingStars(stars) => self.rating_stars = stars,
+            Message::EditedRatingNote(note) => self.rating_note = note,
+            Message::SavedRecipeRating => {
+                if let Some(recipe_name) = self.recipe_in_rating.take()
+                    && let Err(error) =
+                        self.recipe_service.rate_recipe(&recipe_name, self.rating_stars, self.rating_note.clone())
+                {
+                    // The rating is kept for the session, the error telling it isn't saved
+                    self.recipe_stats_error = Some(error);
+                }
+                self.current_view = View::Main;
+            }
             Message::FilteredSlotRecipe(string) => {
                 self.filters_on_recipes_slots
                     .insert(self.slot_currently_in_edition.unwrap(), string);
+                self.highlighted_recipe = 0;
             }
             Message::SelectedRecipe(recipe_slot, recipe) => {
                 if let Some(selected_recipe_name) = recipe {
-                    let selected_recipe = self
+                    let mut selected_recipe = self
                         .recipe_service
                         .find_recipe_by_name(&selected_recipe_name).unwrap();
+                    if self.household_service.has_members() {
+                        selected_recipe.configured_nbr_persons =
+                            self.household_service.nbr_persons(self.absent_members_of(recipe_slot), self.allow_half_portions);
+                    } else if let Some(default_nbr_persons) = self.settings.default_nbr_persons {
+                        selected_recipe.configured_nbr_persons = PersonCount::from_whole(default_nbr_persons);
+                    }
 
+                    self.record_recipe_planned(&selected_recipe_name);
                     self.selected_recipes.insert(recipe_slot, selected_recipe);
+                    self.nbr_persons_inputs.remove(&recipe_slot);
                 } else {
                     if self.selected_recipes.contains_key(&recipe_slot) {
                         self.selected_recipes.remove(&recipe_slot);
@@ -156,88 +815,106 @@ impl MainController {
                 self.current_view = View::Main;
             }
             Message::IncrementedNbrPersonsOfRecipe(recipe_slot, nbr_persons) => {
-                let mut recipe = self.selected_recipes.get(&recipe_slot).unwrap().clone();
-                recipe.configured_nbr_persons = recipe.configured_nbr_persons + nbr_persons;
-                self.selected_recipes.remove(&recipe_slot);
-                self.selected_recipes.insert(recipe_slot, recipe.clone());
+                if let Some(recipe) = self.selected_recipes.get_mut(&recipe_slot) {
+                    recipe.configured_nbr_persons = recipe.configured_nbr_persons.saturating_add(nbr_persons);
+                }
+                self.nbr_persons_inputs.remove(&recipe_slot);
             }
             Message::DecrementedNbrPersonsOfRecipe(recipe_slot, nbr_persons) => {
-                let mut recipe = self.selected_recipes.get(&recipe_slot).unwrap().clone();
-                recipe.configured_nbr_persons = {
-                    if recipe.configured_nbr_persons == 0 {
-                        0u8
-                    } else {
-                        recipe.configured_nbr_persons - nbr_persons
+                if let Some(recipe) = self.selected_recipes.get_mut(&recipe_slot) {
+                    recipe.configured_nbr_persons = recipe.configured_nbr_persons.saturating_sub(nbr_persons);
+                }
+                self.nbr_persons_inputs.remove(&recipe_slot);
+            }
+            Message::EditedNbrPersonsOfRecipe(recipe_slot, mut nbr_persons_input) => {
+                if let Ok(mut nbr_persons) = PersonCount::parse(&nbr_persons_input, self.settings.language)
+                    && let Some(recipe) = self.selected_recipes.get_mut(&recipe_slot)
+                {
+                    // Without half portions, a half typed is rounded up as the +/- buttons would
+                    if !self.allow_half_portions && nbr_persons != nbr_persons.ceil() {
+                        nbr_persons = nbr_persons.ceil();
+                        nbr_persons_input = nbr_persons.label(self.settings.language);
                     }
-                };
-                self.selected_recipes.remove(&recipe_slot);
-                self.selected_recipes.insert(recipe_slot, recipe.clone());
+                    recipe.configured_nbr_persons = nbr_persons;
+                }
+                self.nbr_persons_inputs.insert(recipe_slot, nbr_persons_input);
+            }
+            Message::ResetNbrPersonsOfRecipe(recipe_slot) => {
+                if let Some(recipe) = self.selected_recipes.get_mut(&recipe_slot) {
+                    recipe.reset_configured_nbr_persons();
+                }
+                self.nbr_persons_inputs.remove(&recipe_slot);
+            }
+            Message::ToggledHalfPortions(allow_half_portions) => {
+                self.allow_half_portions = allow_half_portions;
+                if !allow_half_portions {
+                    for recipe in self.selected_recipes.values_mut() {
+                        recipe.configured_nbr_persons = recipe.configured_nbr_persons.ceil();
+                    }
+                    self.nbr_persons_inputs.clear();
+                }
             }
             Message::GenerateRecipeDocument => {
+                let synced_recipe_of = |recipe_slot: &RecipeSlot| {
+                    self.selected_recipes.get(recipe_slot).map(|recipe| {
+                        let mut recipe = recipe.clone();
+                        recipe.sync_with_configured_nbr_persons();
+                        recipe
+                    })
+                };
                 let mut week_days_to_print: Vec<WeekDay> = Vec::new();
-                let noon_slots: [RecipeSlot; 7] = [
-                    RecipeSlot::MondayNoon,
-                    RecipeSlot::TuesdayNoon,
-                    RecipeSlot::WednesdayNoon,
-                    RecipeSlot::ThursdayNoon,
-                    RecipeSlot::FridayNoon,
-                    RecipeSlot::SaturdayNoon,
-                    RecipeSlot::SundayNoon,
-                ];
-
-                for (recipe_slot, recipe) in self.selected_recipes.iter() {
-                    let mut recipe = recipe.clone();
-                    recipe.sync_with_configured_nbr_persons();
-                    for week_day in self.week_days.iter_mut() {
-                        let mut week_day = week_day.clone();
-                        let mut should_write_day = false;
-
-                        if week_day.noon_recipe_slot == recipe_slot.clone() {
-                            week_day.noon_recipe = Some(recipe.clone());
-                            should_write_day = true;
-                        }
-
-                        if week_day.evening_recipe_slot == recipe_slot.clone() {
-                            week_day.evening_recipe = Some(recipe.clone());
-                            should_write_day = true;
-                        }
-
-                        if should_write_day {
-                            let day_index = week_days_to_print
-                                .iter()
-                                .position(|wd| wd.name == week_day.name);
-                            match day_index {
-                                Some(index) => {
-                                    let mut new_week_day = week_days_to_print[index].clone();
-                                    week_days_to_print.remove(index);
-                                    if noon_slots.contains(recipe_slot) {
-                                        new_week_day.noon_recipe = week_day.noon_recipe.clone();
-                                    } else {
-                                        new_week_day.evening_recipe =
-                                            week_day.evening_recipe.clone();
-                                    }
-                                    week_days_to_print.push(new_week_day);
-                                }
-                                None => {
-                                    week_days_to_print.push(week_day);
-                                }
-                            }
-                        }
+                for week_day in self.week_days.iter() {
+                    let mut week_day = week_day.clone();
+                    week_day.noon_recipe = synced_recipe_of(&week_day.noon_recipe_slot);
+                    week_day.evening_recipe = synced_recipe_of(&week_day.evening_recipe_slot);
+                    if week_day.noon_recipe.is_some() || week_day.evening_recipe.is_some() {
+                        week_days_to_print.push(week_day);
                     }
                 }
 
-                let all_recipes: Vec<Recipe> = self.selected_recipes.values().cloned().collect();
-                let all_ingredients: Vec<Ingredient> = self
-                    .recipe_service
-                    .gather_all_ingredients_from_recipes_vector(&all_recipes);
+                let (all_ingredients, shopping_list_sources): (Vec<Ingredient>, Vec<_>) = self
+                    .shopping_list()
+                    .into_iter()
+                    .filter(|line| !line.is_ticked)
+                    .map(|line| (line.ingredient, line.sources))
+                    .unzip();
 
-                week_days_to_print.sort_by(|day1, day2| day1.day_position.cmp(&day2.day_position));
+                let nutrition_estimates = self
+                    .selected_recipes
+                    .iter()
+                    .map(|(recipe_slot, recipe)| (*recipe_slot, self.catalogue_service.nutrition_estimate(recipe)))
+                    .collect();
+                let recipe_costs = self
+                    .selected_recipes
+                    .iter()
+                    .map(|(recipe_slot, recipe)| (*recipe_slot, self.catalogue_service.recipe_cost_estimate(recipe)))
+                    .collect();
+                let shopping_list_cost = self.catalogue_service.cost_estimate(&all_ingredients);
+                let package_plans = all_ingredients
+                    .iter()
+                    .map(|ingredient| self.catalogue_service.package_plan(ingredient))
+                    .collect();
+                let leftover_suggestions = self.leftover_suggestions.clone();
 
                 let menu = Menu {
                     all_ingredients,
+                    shopping_list_sources,
                     week_days: week_days_to_print,
+                    nutrition_estimates,
+                    shopping_list_cost,
+                    recipe_costs,
+                    package_plans,
+                    leftover_suggestions,
                 };
-                write_excel_menu(&menu);
+                if let Err(error) = write_excel_menu(&menu, &self.settings) {
+                    let texts = self.settings.language.texts();
+                    let _ = DialogBuilder::message()
+                        .set_level(MessageLevel::Error)
+                        .set_title(texts.generate_menu)
+                        .set_text(format!("{}{error}", texts.menu_not_written))
+                        .alert()
+                        .show();
+                }
             },
             Message::ImportExcelFile => {
                 let loaded_week_days = read_from_excel_menu(&self.recipe_service, self.week_days.clone());
@@ -252,6 +929,61 @@ impl MainController {
                     }
                 }
             },
+            Message::ConfirmedHighlightedRecipe => {
+                if let Some(recipe_name) = self.listed_recipe_names().get(self.highlighted_recipe) {
+                    let recipe_slot = self.slot_currently_in_edition.unwrap();
+                    self.apply(Message::SelectedRecipe(recipe_slot, Some(recipe_name.clone())));
+                }
+            }
+            Message::Undo | Message::Redo | Message::KeyPressed(..) => {}
+            Message::ImportRecipeFile => {
+                let texts = self.settings.language.texts();
+                let picked_path = DialogBuilder::file()
+                    .add_filter(texts.recipe_files, IMPORTABLE_EXTENSIONS)
+                    .open_single_file()
+                    .show();
+                let (level, text) = match picked_path {
+                    Ok(None) => return,
+                    Ok(Some(path)) => match self.recipe_service.import_recipe(&path) {
+                        Ok(recipe_file) => (MessageLevel::Info, format!("{}{}", texts.recipe_imported_in, recipe_file.display())),
+                        Err(error) => (MessageLevel::Error, format!("{}{error}", texts.recipe_not_imported)),
+                    },
+                    Err(error) => (MessageLevel::Error, format!("{}{error}", texts.file_not_picked)),
+                };
+                let _ = DialogBuilder::message()
+                    .set_level(level)
+                    .set_title(texts.import_recipe)
+                    .set_text(text)
+                    .alert()
+                    .show();
+            },
+        }
+    }
+}
+
+/// Tells at startup that a data file couldn't be loaded, the application going on without it.
+fn alert_loading_error(consequence: &str, error: &str, language: Language) {
+    let _ = DialogBuilder::message()
+        .set_level(MessageLevel::Error)
+        .set_title(language.texts().loading_error)
+        .set_text(format!("{consequence}{error}"))
+        .alert()
+        .show();
+}
+
+/// The menu can only be generated in an existing directory we are allowed to write in,
+/// a bare file name being written in the working directory.
+fn check_output_directory(settings: &Settings, language: Language) -> Result<(), String> {
+    let texts = language.texts();
+    let output_directory = match std::path::Path::new(settings.output_file.trim()).parent() {
+        Some(output_directory) if !output_directory.as_os_str().is_empty() => output_directory,
+        _ => std::path::Path::new("."),
+    };
+    match std::fs::metadata(output_directory) {
+        Ok(metadata) if metadata.is_dir() && metadata.permissions().readonly() => {
+            Err(format!("{}{}", texts.read_only_output_directory, output_directory.display()))
         }
+        Ok(metadata) if metadata.is_dir() => Ok(()),
+        _ => Err(format!("{}{}", texts.missing_output_directory, output_directory.display())),
     }
 }
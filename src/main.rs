@@ -1,3 +1,4 @@
+use controller::cli_controller;
 use controller::main_controller::MainController;
 
 mod model;
@@ -7,5 +8,12 @@ mod controller;
 mod view;
 
 fn main() -> iced::Result {
-    iced::run("Menus Manager", MainController::update, MainController::view)
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+    if !arguments.is_empty() {
+        std::process::exit(cli_controller::run(&arguments));
+    }
+    iced::application("Menus Manager", MainController::update, MainController::view)
+        .subscription(MainController::subscription)
+        .theme(MainController::theme)
+        .run()
 }
@@ -1,4 +1,22 @@
+pub mod catalogue_entry;
+pub mod diet;
+pub mod household;
 pub mod ingredient;
 pub mod recipe;
+pub mod recipe_file;
+pub mod recipe_metadata;
+pub mod recipe_slot;
+pub mod recipe_stats;
 pub mod weekday;
 pub mod menu;
+pub mod nutrition;
+pub mod person_count;
+pub mod price;
+pub mod package;
+pub mod calendar_date;
+pub mod history;
+pub mod language;
+pub mod settings;
+pub mod theme;
+pub mod dashboard;
+pub mod shopping_list;
@@ -0,0 +1,168 @@
+use crate::model::language::Language;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+// The 1st of January 1970 was a Thursday, 3 days after a Monday
+const EPOCH_DAYS_SINCE_MONDAY: i64 = 3;
+
+/// A day of the calendar, used to know the month each planned meal falls in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl CalendarDate {
+    /// The date in the time zone of the user, so that the week doesn't change at 1 or 2 am.
+    pub fn today() -> CalendarDate {
+        let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs() as i64).unwrap_or(0);
+        CalendarDate::from_days_since_epoch((seconds + local_utc_offset(seconds)).div_euclid(SECONDS_PER_DAY))
+    }
+
+    /// Parses a date written "19/10/2026" or "2026-10-19".
+    pub fn parse(text: &str, language: Language) -> Result<CalendarDate, String> {
+        let texts = language.texts();
+        let text = text.trim();
+        let parts: Vec<&str> = text.split(['/', '-']).collect();
+        let numbers: Vec<i32> = parts.iter().filter_map(|part| part.trim().parse::<i32>().ok()).collect();
+        let (year, month, day) = match (numbers.as_slice(), text.contains('/')) {
+            ([day, month, year], true) => (*year, *month, *day),
+            ([year, month, day], false) => (*year, *month, *day),
+            _ => return Err(format!("« {text} » {}", texts.not_a_date)),
+        };
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month as u8) as i32 {
+            return Err(format!("{text} : {}", texts.nonexistent_date));
+        }
+        Ok(CalendarDate { year, month: month as u8, day: day as u8 })
+    }
+
+    // Days to civil date conversion, from Howard Hinnant's `civil_from_days`
+    pub fn from_days_since_epoch(days: i64) -> CalendarDate {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+        let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u8;
+        let year = (year_of_era + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+        CalendarDate { year, month, day }
+    }
+
+    pub fn days_since_epoch(&self) -> i64 {
+        let year = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let shifted_month = (self.month as i64 + 9) % 12;
+        let day_of_year = (153 * shifted_month + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    pub fn add_days(&self, days: i64) -> CalendarDate {
+        CalendarDate::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// The Monday of the week of this date.
+    pub fn monday_of_week(&self) -> CalendarDate {
+        let days = self.days_since_epoch();
+        self.add_days(-(days + EPOCH_DAYS_SINCE_MONDAY).rem_euclid(7))
+    }
+
+    pub fn month_name(&self, language: Language) -> &'static str {
+        language.texts().months[self.month as usize - 1]
+    }
+
+    /// "19/10".
+    pub fn short(&self) -> String {
+        format!("{:02}/{:02}", self.day, self.month)
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{:02}/{:02}/{}", self.day, self.month, self.year)
+    }
+}
+
+/// Seconds to add to UTC to get the local time at `seconds` since the epoch.
+#[cfg(unix)]
+fn local_utc_offset(seconds: i64) -> i64 {
+    let time = seconds as libc::time_t;
+    // SAFETY : `tm` is plain data, filled by `localtime_r` which doesn't keep any of the pointers
+    let mut local_time: libc::tm = unsafe { std::mem::zeroed() };
+    match unsafe { libc::localtime_r(&time, &mut local_time) }.is_null() {
+        true => 0,
+        false => local_time.tm_gmtoff as i64,
+    }
+}
+
+// Other platforms stay on UTC
+#[cfg(not(unix))]
+fn local_utc_offset(_seconds: i64) -> i64 {
+    0
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u8, day: u8) -> CalendarDate {
+        CalendarDate { year, month, day }
+    }
+
+    #[test]
+    fn converts_days_since_epoch_both_ways() {
+        assert_eq!(CalendarDate::from_days_since_epoch(0), date(1970, 1, 1));
+        assert_eq!(CalendarDate::from_days_since_epoch(-1), date(1969, 12, 31));
+        for days in [-800_000, -1, 0, 59, 11_016, 20_745, 800_000] {
+            assert_eq!(CalendarDate::from_days_since_epoch(days).days_since_epoch(), days);
+        }
+    }
+
+    #[test]
+    fn handles_leap_years() {
+        assert_eq!(date(2024, 2, 28).add_days(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 2, 28).add_days(1), date(2023, 3, 1));
+        assert_eq!(date(2000, 2, 28).add_days(1), date(2000, 2, 29));
+        assert_eq!(date(2100, 2, 28).add_days(1), date(2100, 3, 1));
+        assert!(CalendarDate::parse("29/02/2024", Language::French).is_ok());
+        assert!(CalendarDate::parse("29/02/2100", Language::French).is_err());
+    }
+
+    #[test]
+    fn crosses_year_boundaries() {
+        assert_eq!(date(2026, 12, 31).add_days(1), date(2027, 1, 1));
+        assert_eq!(date(2027, 1, 1).add_days(-1), date(2026, 12, 31));
+        // The 1st of January 2027 is a Friday
+        assert_eq!(date(2027, 1, 1).monday_of_week(), date(2026, 12, 28));
+    }
+
+    #[test]
+    fn the_monday_of_a_sunday_is_six_days_before() {
+        assert_eq!(date(2026, 10, 25).monday_of_week(), date(2026, 10, 19));
+        assert_eq!(date(2026, 10, 19).monday_of_week(), date(2026, 10, 19));
+        assert_eq!(date(1970, 1, 4).monday_of_week(), date(1969, 12, 29));
+    }
+
+    #[test]
+    fn parses_both_date_orders_and_rejects_the_others() {
+        assert_eq!(CalendarDate::parse("19/10/2026", Language::French), Ok(date(2026, 10, 19)));
+        assert_eq!(CalendarDate::parse(" 2026-10-19 ", Language::French), Ok(date(2026, 10, 19)));
+        for text in ["", "19/10", "2026/10/19/1", "lundi", "31/04/2026", "00/10/2026", "19/13/2026", "19/10/deux"] {
+            assert!(CalendarDate::parse(text, Language::French).is_err(), "{text}");
+        }
+    }
+}
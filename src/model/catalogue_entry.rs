@@ -0,0 +1,25 @@
+use crate::model::diet::DietaryTag;
+use crate::model::nutrition::NutritionEntry;
+use crate::model::package::Package;
+use crate::model::price::Price;
+use serde::{Deserialize, Serialize};
+
+/// What we know about an ingredient, as written in the ingredient catalogue.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CatalogueEntry {
+    #[serde(default)]
+    pub tags: Vec<DietaryTag>,
+    #[serde(default)]
+    pub nutrition: Option<NutritionEntry>,
+    // Grams of a piece, for the ingredients counted one by one
+    #[serde(default)]
+    pub unit_weight: Option<f32>,
+    #[serde(default)]
+    pub prices: Vec<Price>,
+    // Sizes in which the ingredient is sold, the one leaving the least leftover being bought
+    #[serde(default)]
+    pub packages: Vec<Package>,
+    // Months, from 1 to 12, when a fruit or a vegetable is in season, empty all year round
+    #[serde(default)]
+    pub months: Vec<u8>,
+}
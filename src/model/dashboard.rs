@@ -0,0 +1,30 @@
+use crate::model::person_count::PersonCount;
+use crate::model::recipe_slot::RecipeSlot;
+
+/// Overview of the planned week, shown above the buttons of the main view.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeekDashboard {
+    pub nbr_planned_meals: usize,
+    pub nbr_veggie_meals: usize,
+    pub total_portions: PersonCount,
+    // Lines of the shopping list, an ingredient being listed once per unit
+    pub nbr_shopping_items: usize,
+    // Preparation and cooking of the planned meals, in minutes
+    pub kitchen_time: u16,
+    pub nbr_meals_without_time: usize,
+    pub warnings: Vec<DashboardWarning>,
+}
+
+impl WeekDashboard {
+    pub fn nbr_other_meals(&self) -> usize {
+        self.nbr_planned_meals - self.nbr_veggie_meals
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DashboardWarning {
+    EmptySlots(Vec<RecipeSlot>),
+    RepeatedRecipe { recipe_name: String, nbr_times: usize },
+    // Planned recipe no longer among the loaded ones, after its file was removed or renamed
+    MissingRecipe { recipe_name: String, recipe_slot: RecipeSlot },
+}
@@ -0,0 +1,167 @@
+use crate::model::language::Language;
+use serde::{Deserialize, Serialize};
+
+/// Allergen or diet attribute of an ingredient, as written in the ingredient catalogue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum DietaryTag {
+    #[serde(rename = "gluten")]
+    Gluten,
+    #[serde(rename = "lactose")]
+    Lactose,
+    #[serde(rename = "fruits_a_coque")]
+    TreeNuts,
+    #[serde(rename = "arachide")]
+    Peanut,
+    #[serde(rename = "oeuf")]
+    Egg,
+    #[serde(rename = "poisson")]
+    Fish,
+    #[serde(rename = "crustaces")]
+    Shellfish,
+    #[serde(rename = "porc")]
+    Pork,
+    #[serde(rename = "viande")]
+    Meat,
+    // Any other product coming from an animal (honey, gelatine, ...)
+    #[serde(rename = "produit_animal")]
+    AnimalProduct,
+}
+
+impl DietaryTag {
+    pub const ALL: [DietaryTag; 10] = [
+        DietaryTag::Gluten,
+        DietaryTag::Lactose,
+        DietaryTag::TreeNuts,
+        DietaryTag::Peanut,
+        DietaryTag::Egg,
+        DietaryTag::Fish,
+        DietaryTag::Shellfish,
+        DietaryTag::Pork,
+        DietaryTag::Meat,
+        DietaryTag::AnimalProduct,
+    ];
+
+    pub fn label(&self, language: Language) -> &'static str {
+        language.texts().dietary_tags[DietaryTag::ALL.iter().position(|tag| tag == self).unwrap()]
+    }
+}
+
+/// What a member of the household can't eat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DietaryRestriction {
+    #[serde(rename = "sans_gluten")]
+    GlutenFree,
+    #[serde(rename = "sans_lactose")]
+    LactoseFree,
+    #[serde(rename = "sans_fruits_a_coque")]
+    TreeNutFree,
+    #[serde(rename = "sans_porc")]
+    PorkFree,
+    #[serde(rename = "sans_poisson")]
+    FishFree,
+    #[serde(rename = "vegetarien")]
+    Vegetarian,
+    #[serde(rename = "vegan")]
+    Vegan,
+}
+
+impl DietaryRestriction {
+    pub const ALL: [DietaryRestriction; 7] = [
+        DietaryRestriction::GlutenFree,
+        DietaryRestriction::LactoseFree,
+        DietaryRestriction::TreeNutFree,
+        DietaryRestriction::PorkFree,
+        DietaryRestriction::FishFree,
+        DietaryRestriction::Vegetarian,
+        DietaryRestriction::Vegan,
+    ];
+
+    pub fn label(&self, language: Language) -> &'static str {
+        language.texts().restrictions[DietaryRestriction::ALL.iter().position(|restriction| restriction == self).unwrap()]
+    }
+
+    pub fn forbidden_tags(&self) -> &'static [DietaryTag] {
+        match self {
+            DietaryRestriction::GlutenFree => &[DietaryTag::Gluten],
+            DietaryRestriction::LactoseFree => &[DietaryTag::Lactose],
+            DietaryRestriction::TreeNutFree => &[DietaryTag::TreeNuts],
+            DietaryRestriction::PorkFree => &[DietaryTag::Pork],
+            DietaryRestriction::FishFree => &[DietaryTag::Fish],
+            DietaryRestriction::Vegetarian => &[
+                DietaryTag::Meat,
+                DietaryTag::Pork,
+                DietaryTag::Fish,
+                DietaryTag::Shellfish,
+            ],
+            DietaryRestriction::Vegan => &[
+                DietaryTag::Meat,
+                DietaryTag::Pork,
+                DietaryTag::Fish,
+                DietaryTag::Shellfish,
+                DietaryTag::Lactose,
+                DietaryTag::Egg,
+                DietaryTag::AnimalProduct,
+            ],
+        }
+    }
+}
+
+/// Diet attributes of a recipe, derived from the catalogue entries of its ingredients.
+#[derive(Clone, Debug, Default)]
+pub struct RecipeDietProfile {
+    // Ingredients having at least one tag, with their tags
+    pub tagged_ingredients: Vec<(String, Vec<DietaryTag>)>,
+    // Ingredients missing from the catalogue, nothing can be said about them
+    pub unknown_ingredients: Vec<String>,
+}
+
+impl RecipeDietProfile {
+    pub fn tags(&self) -> Vec<DietaryTag> {
+        let mut tags: Vec<DietaryTag> = self
+            .tagged_ingredients
+            .iter()
+            .flat_map(|(_, tags)| tags.iter().copied())
+            .collect();
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
+    /// Diet flags derived from the ingredients, only given when every ingredient is known.
+    pub fn diet_label(&self, language: Language) -> Option<&'static str> {
+        if !self.unknown_ingredients.is_empty() {
+            None
+        } else if self.satisfies(DietaryRestriction::Vegan) {
+            Some(DietaryRestriction::Vegan.label(language))
+        } else if self.satisfies(DietaryRestriction::Vegetarian) {
+            Some(DietaryRestriction::Vegetarian.label(language))
+        } else {
+            None
+        }
+    }
+
+    pub fn conflicting_ingredients(&self, restriction: DietaryRestriction) -> Vec<String> {
+        self.tagged_ingredients
+            .iter()
+            .filter(|(_, tags)| tags.iter().any(|tag| restriction.forbidden_tags().contains(tag)))
+            .map(|(ingredient, _)| ingredient.clone())
+            .collect()
+    }
+
+    pub fn satisfies(&self, restriction: DietaryRestriction) -> bool {
+        self.conflicting_ingredients(restriction).is_empty()
+    }
+
+    /// Warns when the `Veggie:` line of the recipe contradicts its ingredients.
+    pub fn veggie_inconsistency(&self, declared_veggie: bool, language: Language) -> Option<String> {
+        let texts = language.texts();
+        let non_veggie_ingredients = self.conflicting_ingredients(DietaryRestriction::Vegetarian);
+        if declared_veggie && !non_veggie_ingredients.is_empty() {
+            Some(format!("{}{}", texts.declared_veggie_but, non_veggie_ingredients.join(", ")))
+        } else if !declared_veggie && non_veggie_ingredients.is_empty() && self.unknown_ingredients.is_empty() {
+            Some(texts.declared_not_veggie.to_string())
+        } else {
+            None
+        }
+    }
+}
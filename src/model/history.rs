@@ -0,0 +1,115 @@
+use std::collections::VecDeque;
+
+pub const MAX_HISTORY_SIZE: usize = 50;
+
+/// Past and undone states of something being edited, the oldest states being forgotten
+/// once `capacity` is reached.
+#[derive(Clone, Debug)]
+pub struct History<T> {
+    undo_stack: VecDeque<T>,
+    redo_stack: Vec<T>,
+    capacity: usize,
+}
+
+impl<T> Default for History<T> {
+    fn default() -> Self {
+        History::with_capacity(MAX_HISTORY_SIZE)
+    }
+}
+
+impl<T> History<T> {
+    pub fn with_capacity(capacity: usize) -> History<T> {
+        History {
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            capacity,
+        }
+    }
+
+    /// Records the state preceding an edit, which makes the undone states unreachable.
+    pub fn record(&mut self, previous_state: T) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.undo_stack.len() == self.capacity {
+            self.undo_stack.pop_front();
+        }
+        self.undo_stack.push_back(previous_state);
+        self.redo_stack.clear();
+    }
+
+    /// Returns the state to go back to, keeping `current_state` to redo it.
+    pub fn undo(&mut self, current_state: T) -> Option<T> {
+        let previous_state = self.undo_stack.pop_back()?;
+        self.redo_stack.push(current_state);
+        Some(previous_state)
+    }
+
+    /// Returns the last undone state, keeping `current_state` to undo it again.
+    pub fn redo(&mut self, current_state: T) -> Option<T> {
+        let next_state = self.redo_stack.pop()?;
+        self.undo_stack.push_back(current_state);
+        Some(next_state)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_then_redo_walks_through_the_states() {
+        let mut history = History::default();
+        history.record(1);
+        history.record(2);
+        // The current state is 3
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), Some(1));
+        assert_eq!(history.undo(1), None);
+        assert_eq!(history.redo(1), Some(2));
+        assert_eq!(history.redo(2), Some(3));
+        assert_eq!(history.redo(3), None);
+    }
+
+    #[test]
+    fn recording_forgets_the_undone_states() {
+        let mut history = History::default();
+        history.record("a");
+        assert_eq!(history.undo("b"), Some("a"));
+        assert!(history.can_redo());
+        history.record("a");
+        assert!(!history.can_redo());
+        assert_eq!(history.redo("c"), None);
+    }
+
+    #[test]
+    fn the_oldest_states_are_dropped_beyond_capacity() {
+        let mut history = History::with_capacity(2);
+        history.record(1);
+        history.record(2);
+        history.record(3);
+        assert_eq!(history.undo(4), Some(3));
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), None);
+    }
+
+    #[test]
+    fn nothing_to_undo_or_redo_at_first() {
+        let mut history: History<u8> = History::default();
+        assert!(!history.can_undo());
+        assert!(!history.can_redo());
+        assert_eq!(history.undo(0), None);
+        assert_eq!(history.redo(0), None);
+        let mut disabled_history = History::with_capacity(0);
+        disabled_history.record(1);
+        assert!(!disabled_history.can_undo());
+    }
+}
@@ -0,0 +1,24 @@
+use crate::model::diet::DietaryRestriction;
+use serde::{Deserialize, Serialize};
+
+pub const ADULT_PORTION: f32 = 1.0;
+
+/// Someone eating at home, a child usually eating a fraction of an adult portion.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HouseholdMember {
+    pub name: String,
+    #[serde(default = "adult_portion")]
+    pub portion: f32,
+    #[serde(default)]
+    pub restrictions: Vec<DietaryRestriction>,
+}
+
+fn adult_portion() -> f32 {
+    ADULT_PORTION
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Household {
+    #[serde(default)]
+    pub members: Vec<HouseholdMember>,
+}
@@ -1,8 +1,197 @@
 pub const WHOLE_INGREDIENT: &str = "__WHOLE_INGREDIENT__";
 
-#[derive(Clone, Debug)]
+// Units of ingredients bought as a whole : scaled quantities are rounded up
+const PACKAGE_UNITS: [&str; 14] = [
+    "pot", "boite", "boîte", "motte", "sachet", "paquet", "bocal", "brique", "conserve", "barquette", "tablette",
+    "rouleau", "bouteille", "canette",
+];
+// Units of ingredients counted one by one, as are the ingredients without unit
+const COUNT_UNITS: [&str; 11] = [
+    "tranche", "gousse", "feuille", "portion", "pièce", "branche", "brin", "pavé", "filet", "bouquet", "botte",
+];
+// Approximate weight in grams of one unit, liquids being counted as water
+const UNIT_WEIGHTS: [(&str, f32); 14] = [
+    ("g", 1.0),
+    ("gr", 1.0),
+    ("gramme", 1.0),
+    ("kg", 1000.0),
+    ("ml", 1.0),
+    ("cl", 10.0),
+    ("dl", 100.0),
+    ("l", 1000.0),
+    ("cs", 15.0),
+    ("càs", 15.0),
+    ("cc", 5.0),
+    ("càc", 5.0),
+    ("pincée", 0.5),
+    ("pincee", 0.5),
+];
+// Seasonings are not scaled linearly : twice the people doesn't need twice the salt
+const SEASONING_UNITS: [&str; 2] = ["pincée", "pincee"];
+const SEASONING_NAMES: [&str; 16] = [
+    "sel", "poivre", "épice", "epice", "piment", "paprika", "cumin", "curry", "muscade", "cannelle", "curcuma",
+    "gingembre", "origan", "thym", "gomasio", "harissa",
+];
+// Spoons are measured by quarters
+const SPOON_UNITS: [&str; 8] = ["cs", "cc", "càs", "càc", "cas", "cac", "cuillère", "cuillere"];
+const SEASONING_SCALING_EXPONENT: f32 = 0.75;
+// Margin avoiding to round 2.0001 up to 3 after a float computation
+const ROUNDING_UP_TOLERANCE: f32 = 0.05;
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Ingredient {
     pub name: String,
     pub unit: String,
     pub quantity: f32,
 }
+
+/// How the quantity of an ingredient follows the number of persons.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScalingRule {
+    Linear,
+    WholeUnits,
+    Packages,
+    Seasoning,
+}
+
+impl Ingredient {
+    /// Lowercased unit without its plural mark, "tranches" giving "tranche".
+    pub fn unit_key(&self) -> String {
+        singular(&self.unit.trim().to_lowercase())
+    }
+
+    /// Whether the ingredient is counted one by one : "2 oignons", "3 tranches : jambon".
+    pub fn is_counted(&self) -> bool {
+        self.unit == WHOLE_INGREDIENT || COUNT_UNITS.contains(&self.unit_key().as_str())
+    }
+
+    /// Approximate weight in grams, counted ingredients needing the weight of a piece.
+    pub fn grams(&self, unit_weight: Option<f32>) -> Option<f32> {
+        match unit_grams(&self.unit) {
+            Some(unit_grams) => Some(self.quantity * unit_grams),
+            None if self.is_counted() => Some(self.quantity * unit_weight?),
+            None => None,
+        }
+    }
+
+    pub fn scaling_rule(&self) -> ScalingRule {
+        let unit = self.unit_key();
+        let name = self.name.to_lowercase();
+        let is_seasoning = SEASONING_UNITS.contains(&unit.as_str())
+            || name
+                .split(|character: char| !character.is_alphanumeric())
+                .any(|word| SEASONING_NAMES.contains(&singular(word).as_str()));
+
+        if is_seasoning {
+            ScalingRule::Seasoning
+        } else if PACKAGE_UNITS.contains(&unit.as_str()) {
+            ScalingRule::Packages
+        } else if self.is_counted() {
+            ScalingRule::WholeUnits
+        } else {
+            ScalingRule::Linear
+        }
+    }
+
+    /// Quantity for `scaling_factor` times the persons of the recipe, rounded the way one would
+    /// cook it : "1.33 oignons" become 2 oignons and "0.67 pot" a whole pot.
+    pub fn scaled_quantity(&self, scaling_factor: f32) -> f32 {
+        if scaling_factor == 1.0 || self.quantity == 0.0 {
+            return self.quantity;
+        }
+        // Nobody eats at this meal : the minimum of one onion or one pot only applies to real portions
+        if scaling_factor == 0.0 {
+            return 0.0;
+        }
+        match self.scaling_rule() {
+            ScalingRule::Linear => match SPOON_UNITS.contains(&self.unit_key().as_str()) {
+                true => round_to_quarter(self.quantity * scaling_factor),
+                false => round_smartly(self.quantity * scaling_factor),
+            },
+            ScalingRule::WholeUnits | ScalingRule::Packages => {
+                (self.quantity * scaling_factor - ROUNDING_UP_TOLERANCE).ceil().max(1.0)
+            }
+            ScalingRule::Seasoning => {
+                let quantity = self.quantity * scaling_factor.powf(SEASONING_SCALING_EXPONENT);
+                match SEASONING_UNITS.contains(&self.unit.trim().to_lowercase().as_str()) {
+                    true => quantity.round().max(1.0),
+                    false => round_to_quarter(quantity),
+                }
+            }
+        }
+    }
+}
+
+fn singular(word: &str) -> String {
+    match word.chars().count() > 3 {
+        true => word.strip_suffix('s').unwrap_or(word).to_string(),
+        false => word.to_string(),
+    }
+}
+
+/// Approximate weight in grams of one unit of measure ("cl", "kg", "cs"...), if known.
+pub fn unit_grams(unit: &str) -> Option<f32> {
+    let unit = singular(&unit.trim().to_lowercase());
+    UNIT_WEIGHTS
+        .iter()
+        .find(|(known_unit, _)| *known_unit == unit)
+        .map(|(_, unit_grams)| *unit_grams)
+}
+
+// Grams and millilitres don't need decimals, smaller quantities keep two of them
+pub fn round_smartly(quantity: f32) -> f32 {
+    match quantity >= 10.0 {
+        true => quantity.round(),
+        false => (quantity * 100.0).round() / 100.0,
+    }
+}
+
+/// "200 g", or just "2" for ingredients counted without unit.
+pub fn format_quantity(quantity: f32, unit: &str) -> String {
+    match unit {
+        WHOLE_INGREDIENT => round_smartly(quantity).to_string(),
+        unit => format!("{} {unit}", round_smartly(quantity)),
+    }
+}
+
+fn round_to_quarter(quantity: f32) -> f32 {
+    ((quantity * 4.0).round() / 4.0).max(0.25)
+}
+
+/// Parses a quantity such as `2`, `1.5`, `1,5`, `1/2`, `½` or `1 ½`.
+pub fn parse_quantity(text: &str) -> Option<f32> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    if let Some((whole_part, fraction_part)) = text.split_once(char::is_whitespace) {
+        return Some(parse_quantity(whole_part)? + parse_quantity(fraction_part)?);
+    }
+    if let Some((numerator, denominator)) = text.split_once('/') {
+        let numerator = numerator.parse::<f32>().ok()?;
+        let denominator = denominator.parse::<f32>().ok()?;
+        return (denominator != 0.0).then(|| numerator / denominator);
+    }
+
+    let mut chars = text.chars();
+    let last_char = chars.next_back()?;
+    if let Some(fraction) = unicode_fraction_value(last_char) {
+        let whole_part = chars.as_str();
+        return match whole_part.is_empty() {
+            true => Some(fraction),
+            false => Some(parse_quantity(whole_part)? + fraction),
+        };
+    }
+    text.replace(',', ".").parse::<f32>().ok()
+}
+
+pub fn unicode_fraction_value(character: char) -> Option<f32> {
+    match character {
+        '¼' => Some(0.25),
+        '½' => Some(0.5),
+        '¾' => Some(0.75),
+        '⅓' => Some(1.0 / 3.0),
+        '⅔' => Some(2.0 / 3.0),
+        _ => None,
+    }
+}
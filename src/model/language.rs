@@ -0,0 +1,636 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Environment variables giving the locale, by order of precedence
+const LOCALE_VARIABLES: [&str; 3] = ["LC_ALL", "LC_MESSAGES", "LANG"];
+
+/// Language of the interface and of the exported workbook.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    French,
+    English,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::French, Language::English];
+
+    /// Language of the system locale ("en_GB.UTF-8"), French when it isn't supported.
+    pub fn from_locale() -> Language {
+        LOCALE_VARIABLES
+            .iter()
+            .filter_map(|variable| std::env::var(variable).ok())
+            .find(|locale| !locale.is_empty())
+            .and_then(|locale| Language::parse(&locale))
+            .unwrap_or_default()
+    }
+
+    /// Parses a language code or locale : "fr", "en", "en_US.UTF-8".
+    pub fn parse(text: &str) -> Option<Language> {
+        match text.trim().to_lowercase().get(..2)? {
+            "fr" => Some(Language::French),
+            "en" => Some(Language::English),
+            _ => None,
+        }
+    }
+
+    pub fn texts(&self) -> &'static Texts {
+        match self {
+            Language::French => &FRENCH_TEXTS,
+            Language::English => &ENGLISH_TEXTS,
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Language::French => "Français",
+            Language::English => "English",
+        };
+        write!(formatter, "{label}")
+    }
+}
+
+/// A value shown with a translated label, for the pick lists.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Labelled<T> {
+    pub value: T,
+    pub label: &'static str,
+}
+
+impl<T> fmt::Display for Labelled<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.label)
+    }
+}
+
+/// User-facing texts of a language. Labels followed by a value end with their separator
+/// ("Contient : " in French, "Contains: " in English).
+pub struct Texts {
+    // Days from Monday to Sunday, meals from noon to evening, months from January
+    pub days: [&'static str; 7],
+    pub meals: [&'static str; 2],
+    pub months: [&'static str; 12],
+    // In the order of RecipeSortKey::ALL and DietaryRestriction::ALL
+    pub sort_keys: [&'static str; 5],
+    pub restrictions: [&'static str; 7],
+    // In the order of ThemeMode::ALL
+    pub theme_modes: [&'static str; 3],
+    // In the order of DietaryTag::ALL and Difficulty::ALL
+    pub dietary_tags: [&'static str; 10],
+    pub difficulties: [&'static str; 3],
+
+    // Main view
+    pub select_recipe: &'static str,
+    pub who_eats: &'static str,
+    pub out_of_season: &'static str,
+    pub not_suitable_for: &'static str,
+    pub per_portion: &'static str,
+    pub without_nutrition_data: &'static str,
+    pub unconvertible_unit: &'static str,
+    pub per_person: &'static str,
+    pub persons_input: &'static str,
+    pub default_persons: &'static str,
+    pub rate: &'static str,
+    pub cancel: &'static str,
+    pub swap: &'static str,
+    pub replace: &'static str,
+    pub paste: &'static str,
+    pub cut: &'static str,
+    pub copy: &'static str,
+    pub per_day: &'static str,
+    pub week_shopping: &'static str,
+    pub without_price: &'static str,
+    pub leftover: &'static str,
+    pub declared_veggie_but: &'static str,
+    pub declared_not_veggie: &'static str,
+    pub by_author: &'static str,
+    pub package_of: &'static str,
+    pub package_leftover: &'static str,
+    pub default_package: &'static str,
+    pub not_a_date: &'static str,
+    pub nonexistent_date: &'static str,
+    pub monday: &'static str,
+    pub undo: &'static str,
+    pub redo: &'static str,
+    pub generate_menu: &'static str,
+    pub import_menu: &'static str,
+    pub import_recipe: &'static str,
+    pub recipe_files: &'static str,
+    pub recipe_imported_in: &'static str,
+    pub recipe_not_imported: &'static str,
+    pub file_not_picked: &'static str,
+    pub week_of: &'static str,
+    pub date_placeholder: &'static str,
+    pub half_portions: &'static str,
+    pub cut_pending: &'static str,
+    pub copied_pending: &'static str,
+    pub done: &'static str,
+    pub keyboard_shortcuts_help: &'static str,
+    pub planned_meals: &'static str,
+    pub veggie_meals: &'static str,
+    pub other_meals: &'static str,
+    pub portions: &'static str,
+    pub shopping_items: &'static str,
+    pub kitchen_time: &'static str,
+    pub meals_without_time: &'static str,
+    pub empty_slots: &'static str,
+    pub in_the_week: &'static str,
+    pub missing_recipe: &'static str,
+
+    // Recipe selection and rating views
+    pub search_placeholder: &'static str,
+    pub query_syntax_help: &'static str,
+    pub missing_from_catalogue: &'static str,
+    pub only_veggies: &'static str,
+    pub only_favourites: &'static str,
+    pub in_season: &'static str,
+    pub household_diets: &'static str,
+    pub no_recipe: &'static str,
+    pub back: &'static str,
+    pub contains: &'static str,
+    pub rate_recipe: &'static str,
+    pub rating_placeholder: &'static str,
+    pub save: &'static str,
+    pub previous_ratings: &'static str,
+
+    // Shopping list view
+    pub shopping_list: &'static str,
+    pub items_to_buy: &'static str,
+    pub already_at_home: &'static str,
+    pub added_by_hand: &'static str,
+    pub edited_quantity: &'static str,
+    pub item_placeholder: &'static str,
+    pub unit_placeholder: &'static str,
+    pub add_item: &'static str,
+    pub remove: &'static str,
+    pub reset_shopping_list: &'static str,
+    pub empty_shopping_list: &'static str,
+    pub item_name_required: &'static str,
+    pub not_a_quantity: &'static str,
+
+    // Settings view
+    pub settings: &'static str,
+    pub recipes_directory: &'static str,
+    pub output_file: &'static str,
+    pub header_colour: &'static str,
+    pub default_nbr_persons: &'static str,
+    pub recipe_nbr_persons: &'static str,
+    pub language: &'static str,
+    pub meal_times: &'static str,
+    pub theme: &'static str,
+    pub accent_colour: &'static str,
+    pub theme_accent: &'static str,
+    pub restore_defaults: &'static str,
+    pub settings_file: &'static str,
+    pub settings_not_loaded: &'static str,
+    pub default_recipes_directory_used: &'static str,
+    pub loading_error: &'static str,
+    pub catalogue_not_loaded: &'static str,
+    pub household_not_loaded: &'static str,
+    pub missing_output_directory: &'static str,
+    pub read_only_output_directory: &'static str,
+    pub menu_not_written: &'static str,
+    pub recipes_directory_required: &'static str,
+    pub output_file_required: &'static str,
+    pub missing_directory: &'static str,
+    pub not_a_nbr_persons: &'static str,
+    pub at_most_persons: &'static str,
+    pub neither_whole_nor_half: &'static str,
+    pub not_a_number: &'static str,
+    pub decimal_separator: &'static str,
+    pub nbr_persons_between: &'static str,
+    pub not_a_colour: &'static str,
+    pub not_a_time: &'static str,
+
+    // Exported workbook
+    pub shopping_list_sheet: &'static str,
+    pub ingredient: &'static str,
+    pub quantity: &'static str,
+    pub estimated_price: &'static str,
+    pub to_buy: &'static str,
+    pub for_meals: &'static str,
+    pub bought: &'static str,
+    pub bought_mark: &'static str,
+    pub bought_help: &'static str,
+    pub week_summary: &'static str,
+    pub persons: &'static str,
+    pub unknown_price: &'static str,
+    pub estimated_total: &'static str,
+    pub ingredients_without_price: &'static str,
+    pub leftovers: &'static str,
+    pub to_finish_with: &'static str,
+    pub preparation: &'static str,
+    pub cooking: &'static str,
+    pub difficulty: &'static str,
+    pub tags: &'static str,
+    pub category: &'static str,
+    pub season: &'static str,
+    pub author: &'static str,
+    pub source: &'static str,
+    pub nutrition_sheet: &'static str,
+    pub nutrition_headers: [&'static str; 10],
+    pub total: &'static str,
+    pub incomplete_estimate: &'static str,
+    pub daily_average: &'static str,
+}
+
+pub const FRENCH_TEXTS: Texts = Texts {
+    days: ["Lundi", "Mardi", "Mercredi", "Jeudi", "Vendredi", "Samedi", "Dimanche"],
+    meals: ["Midi", "Soir"],
+    months: [
+        "janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre",
+        "décembre",
+    ],
+    sort_keys: [
+        "Ordre alphabétique",
+        "Utilisées récemment",
+        "Les plus planifiées",
+        "Favoris d'abord",
+        "Les mieux notées",
+    ],
+    theme_modes: ["Comme le système", "Clair", "Sombre"],
+    dietary_tags: [
+        "gluten",
+        "lactose",
+        "fruits à coque",
+        "arachide",
+        "œuf",
+        "poisson",
+        "crustacés",
+        "porc",
+        "viande",
+        "produit animal",
+    ],
+    difficulties: ["Facile", "Moyenne", "Difficile"],
+    restrictions: [
+        "Sans gluten",
+        "Sans lactose",
+        "Sans fruits à coque",
+        "Sans porc",
+        "Sans poisson",
+        "Végétarien",
+        "Vegan",
+    ],
+
+    select_recipe: "Sélectionnez une recette",
+    who_eats: "Qui mange ?",
+    out_of_season: "Hors saison : ",
+    not_suitable_for: "Ne convient pas à : ",
+    per_portion: "/ portion",
+    without_nutrition_data: "sans données : ",
+    unconvertible_unit: "unité inconvertible : ",
+    per_person: "/ pers.",
+    persons_input: "Pour:",
+    default_persons: "Par défaut",
+    rate: "Noter",
+    cancel: "Annuler",
+    swap: "Échanger",
+    replace: "Remplacer",
+    paste: "Coller",
+    cut: "Couper",
+    copy: "Copier",
+    per_day: "Par jour",
+    week_shopping: "Courses de la semaine ≈ ",
+    without_price: "sans prix : ",
+    leftover: "Reste ",
+    declared_veggie_but: "Déclarée végétarienne mais contient : ",
+    declared_not_veggie: "Déclarée non végétarienne mais aucun ingrédient ne contient de viande ou de poisson",
+    by_author: "par ",
+    package_of: " de ",
+    package_leftover: "reste ",
+    default_package: "paquet",
+    not_a_date: "n'est pas une date (jj/mm/aaaa)",
+    nonexistent_date: "cette date n'existe pas",
+    monday: "lundi ",
+    undo: "↶ Annuler",
+    redo: "↷ Rétablir",
+    generate_menu: "Générer menu",
+    import_menu: "Importer",
+    import_recipe: "Importer une recette",
+    recipe_files: "Recettes",
+    recipe_imported_in: "Recette importée dans ",
+    recipe_not_imported: "Impossible d'importer la recette : ",
+    file_not_picked: "Impossible de choisir le fichier à importer : ",
+    week_of: "Semaine du",
+    date_placeholder: "jj/mm/aaaa",
+    half_portions: "Demi-portions",
+    cut_pending: "à déplacer : choisissez où la coller",
+    copied_pending: "copiée : collez-la sur les autres repas",
+    done: "Terminer",
+    planned_meals: "repas planifiés",
+    veggie_meals: "végétariens",
+    other_meals: "non végétariens",
+    portions: "portions",
+    shopping_items: "articles à acheter",
+    kitchen_time: "en cuisine",
+    meals_without_time: "repas sans durée",
+    empty_slots: "Repas sans recette : ",
+    in_the_week: "dans la semaine",
+    missing_recipe: "n'est plus parmi les recettes chargées",
+    keyboard_shortcuts_help: "Flèches : se déplacer · Entrée : choisir · Échap : retour · Ctrl+G : générer · Ctrl+O : importer · Ctrl+Z / Ctrl+Y : annuler / rétablir",
+
+    search_placeholder: "Rechercher une recette ou un ingrédient...",
+    query_syntax_help: "poulet : nom ou ingrédient · +butternut : avec · -porc : sans · #rapide : tag · <30 : temps max (min) · saison:automne · categorie:dessert · difficulte:facile",
+    missing_from_catalogue: "Ingrédients absents du catalogue :",
+    only_veggies: "Seulement les recettes végétariennes",
+    only_favourites: "Favoris seulement",
+    in_season: "De saison",
+    household_diets: "Régimes du foyer :",
+    no_recipe: " -- AUCUNE RECETTE --",
+    back: "Retour",
+    contains: "Contient : ",
+    rate_recipe: "Noter ",
+    rating_placeholder: "Une remarque après l'avoir cuisinée ?",
+    save: "Enregistrer",
+    previous_ratings: "Notes précédentes",
+
+    shopping_list: "Liste de courses",
+    items_to_buy: "article(s) à acheter",
+    already_at_home: "déjà à la maison",
+    added_by_hand: "ajouté à la main",
+    edited_quantity: "quantité modifiée",
+    item_placeholder: "Article",
+    unit_placeholder: "Unité",
+    add_item: "Ajouter",
+    remove: "Retirer",
+    reset_shopping_list: "Annuler les modifications",
+    empty_shopping_list: "Aucun ingrédient : planifiez des recettes ou ajoutez des articles.",
+    item_name_required: "Le nom de l'article est obligatoire",
+    not_a_quantity: "n'est pas une quantité",
+
+    settings: "Paramètres",
+    recipes_directory: "Dossier des recettes",
+    output_file: "Fichier du menu généré",
+    header_colour: "Couleur des en-têtes (#rrggbb)",
+    default_nbr_persons: "Personnes par défaut, sans foyer",
+    recipe_nbr_persons: "celui de la recette",
+    language: "Langue",
+    meal_times: "Heures des repas",
+    theme: "Thème",
+    accent_colour: "Couleur d'accent (#rrggbb)",
+    theme_accent: "celle du thème",
+    restore_defaults: "Valeurs par défaut",
+    settings_file: "Enregistrés dans ",
+    settings_not_loaded: "Paramètres par défaut utilisés, le fichier ne sera remplacé qu'en enregistrant : ",
+    default_recipes_directory_used: "Dossier des recettes par défaut utilisé : ",
+    loading_error: "Erreur de chargement",
+    catalogue_not_loaded: "Catalogue des ingrédients ignoré, les régimes, nutriments, prix et saisons ne sont pas connus : ",
+    household_not_loaded: "Foyer ignoré, les personnes et les restrictions ne sont pas connues : ",
+    missing_output_directory: "Le dossier du fichier du menu n'existe pas : ",
+    read_only_output_directory: "Le dossier du fichier du menu est en lecture seule : ",
+    menu_not_written: "Impossible d'écrire le menu : ",
+    recipes_directory_required: "Le dossier des recettes est obligatoire",
+    output_file_required: "Le fichier du menu est obligatoire",
+    missing_directory: "Le dossier n'existe pas : ",
+    not_a_nbr_persons: "n'est pas un nombre de personnes",
+    at_most_persons: "Le nombre de personnes ne peut dépasser ",
+    neither_whole_nor_half: "n'est ni un nombre entier ni une demie",
+    not_a_number: "n'est pas un nombre",
+    decimal_separator: ",",
+    nbr_persons_between: "Le nombre de personnes doit être entre 1 et ",
+    not_a_colour: "n'est pas une couleur (#rrggbb)",
+    not_a_time: "n'est pas une heure (hh:mm)",
+
+    shopping_list_sheet: "Liste de courses",
+    ingredient: "Ingrédient",
+    quantity: "Quantité",
+    estimated_price: "Prix estimé",
+    to_buy: "À acheter",
+    for_meals: "Pour",
+    bought: "Acheté",
+    bought_mark: "✓",
+    bought_help: "Choisir ✓ une fois l'article dans le panier",
+    week_summary: "Résumé de la semaine",
+    persons: "Personnes",
+    unknown_price: "prix inconnu",
+    estimated_total: "Total estimé",
+    ingredients_without_price: "ingrédient(s) sans prix",
+    leftovers: "Restes",
+    to_finish_with: "à finir avec : ",
+    preparation: "Préparation",
+    cooking: "Cuisson",
+    difficulty: "Difficulté",
+    tags: "Tags",
+    category: "Catégorie",
+    season: "Saison",
+    author: "Auteur",
+    source: "Source",
+    nutrition_sheet: "Nutrition",
+    nutrition_headers: [
+        "Jour",
+        "Repas",
+        "Recette",
+        "Personnes",
+        "kcal / portion",
+        "Protéines (g)",
+        "Glucides (g)",
+        "Lipides (g)",
+        "Fibres (g)",
+        "Non comptés",
+    ],
+    total: "Total",
+    incomplete_estimate: "Estimation incomplète",
+    daily_average: "Moyenne par jour",
+};
+
+pub const ENGLISH_TEXTS: Texts = Texts {
+    days: ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"],
+    meals: ["Lunch", "Dinner"],
+    months: [
+        "January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November",
+        "December",
+    ],
+    sort_keys: [
+        "Alphabetical order",
+        "Recently used",
+        "Most planned",
+        "Favourites first",
+        "Best rated",
+    ],
+    theme_modes: ["Same as the system", "Light", "Dark"],
+    dietary_tags: [
+        "gluten",
+        "lactose",
+        "tree nuts",
+        "peanut",
+        "egg",
+        "fish",
+        "shellfish",
+        "pork",
+        "meat",
+        "animal product",
+    ],
+    difficulties: ["Easy", "Medium", "Hard"],
+    restrictions: [
+        "Gluten free",
+        "Lactose free",
+        "Tree nut free",
+        "Pork free",
+        "Fish free",
+        "Vegetarian",
+        "Vegan",
+    ],
+
+    select_recipe: "Select a recipe",
+    who_eats: "Who's eating?",
+    out_of_season: "Out of season: ",
+    not_suitable_for: "Not suitable for: ",
+    per_portion: "/ portion",
+    without_nutrition_data: "no data: ",
+    unconvertible_unit: "unconvertible unit: ",
+    per_person: "/ person",
+    persons_input: "For:",
+    default_persons: "Default",
+    rate: "Rate",
+    cancel: "Cancel",
+    swap: "Swap",
+    replace: "Replace",
+    paste: "Paste",
+    cut: "Cut",
+    copy: "Copy",
+    per_day: "Per day",
+    week_shopping: "Week's shopping ≈ ",
+    without_price: "no price: ",
+    leftover: "Left: ",
+    declared_veggie_but: "Declared vegetarian but contains: ",
+    declared_not_veggie: "Declared non vegetarian but no ingredient contains meat or fish",
+    by_author: "by ",
+    package_of: " of ",
+    package_leftover: "left: ",
+    default_package: "pack",
+    not_a_date: "is not a date (dd/mm/yyyy)",
+    nonexistent_date: "this date doesn't exist",
+    monday: "Monday ",
+    undo: "↶ Undo",
+    redo: "↷ Redo",
+    generate_menu: "Generate menu",
+    import_menu: "Import",
+    import_recipe: "Import a recipe",
+    recipe_files: "Recipes",
+    recipe_imported_in: "Recipe imported into ",
+    recipe_not_imported: "Couldn't import the recipe: ",
+    file_not_picked: "Couldn't pick the file to import: ",
+    week_of: "Week of",
+    date_placeholder: "dd/mm/yyyy",
+    half_portions: "Half portions",
+    cut_pending: "to move: choose where to paste it",
+    copied_pending: "copied: paste it on the other meals",
+    done: "Done",
+    planned_meals: "planned meals",
+    veggie_meals: "veggie",
+    other_meals: "non veggie",
+    portions: "portions",
+    shopping_items: "items to buy",
+    kitchen_time: "in the kitchen",
+    meals_without_time: "meals without duration",
+    empty_slots: "Meals without recipe: ",
+    in_the_week: "this week",
+    missing_recipe: "is no longer among the loaded recipes",
+    keyboard_shortcuts_help: "Arrows: move · Enter: choose · Esc: back · Ctrl+G: generate · Ctrl+O: import · Ctrl+Z / Ctrl+Y: undo / redo",
+
+    search_placeholder: "Search a recipe or an ingredient...",
+    query_syntax_help: "chicken: name or ingredient · +butternut: with · -porc: without · #quick: tag · <30: max time (min) · saison:automne · categorie:dessert · difficulte:facile",
+    missing_from_catalogue: "Ingredients missing from the catalogue:",
+    only_veggies: "Vegetarian recipes only",
+    only_favourites: "Favourites only",
+    in_season: "In season",
+    household_diets: "Household diets:",
+    no_recipe: " -- NO RECIPE --",
+    back: "Back",
+    contains: "Contains: ",
+    rate_recipe: "Rate ",
+    rating_placeholder: "Any remark after cooking it?",
+    save: "Save",
+    previous_ratings: "Previous ratings",
+
+    shopping_list: "Shopping list",
+    items_to_buy: "item(s) to buy",
+    already_at_home: "already at home",
+    added_by_hand: "added by hand",
+    edited_quantity: "edited quantity",
+    item_placeholder: "Item",
+    unit_placeholder: "Unit",
+    add_item: "Add",
+    remove: "Remove",
+    reset_shopping_list: "Undo the changes",
+    empty_shopping_list: "No ingredient: plan recipes or add items.",
+    item_name_required: "The name of the item is required",
+    not_a_quantity: "is not a quantity",
+
+    settings: "Settings",
+    recipes_directory: "Recipes directory",
+    output_file: "Generated menu file",
+    header_colour: "Header colour (#rrggbb)",
+    default_nbr_persons: "Default persons, without household",
+    recipe_nbr_persons: "the recipe's own",
+    language: "Language",
+    meal_times: "Meal times",
+    theme: "Theme",
+    accent_colour: "Accent colour (#rrggbb)",
+    theme_accent: "the theme's own",
+    restore_defaults: "Default values",
+    settings_file: "Saved in ",
+    settings_not_loaded: "Using the default settings, the file will only be replaced when saving: ",
+    default_recipes_directory_used: "Using the default recipes directory: ",
+    loading_error: "Loading error",
+    catalogue_not_loaded: "Ignoring the ingredient catalogue, diets, nutrients, prices and seasons are unknown: ",
+    household_not_loaded: "Ignoring the household, its members and restrictions are unknown: ",
+    missing_output_directory: "The directory of the menu file doesn't exist: ",
+    read_only_output_directory: "The directory of the menu file is read-only: ",
+    menu_not_written: "Couldn't write the menu: ",
+    recipes_directory_required: "The recipes directory is required",
+    output_file_required: "The menu file is required",
+    missing_directory: "The directory doesn't exist: ",
+    not_a_nbr_persons: "is not a number of persons",
+    at_most_persons: "The number of persons can't exceed ",
+    neither_whole_nor_half: "is neither a whole number nor a half",
+    not_a_number: "is not a number",
+    decimal_separator: ".",
+    nbr_persons_between: "The number of persons must be between 1 and ",
+    not_a_colour: "is not a colour (#rrggbb)",
+    not_a_time: "is not a time (hh:mm)",
+
+    shopping_list_sheet: "Shopping list",
+    ingredient: "Ingredient",
+    quantity: "Quantity",
+    estimated_price: "Estimated price",
+    to_buy: "To buy",
+    for_meals: "For",
+    bought: "Bought",
+    bought_mark: "✓",
+    bought_help: "Pick ✓ once the item is in the basket",
+    week_summary: "Week summary",
+    persons: "Persons",
+    unknown_price: "unknown price",
+    estimated_total: "Estimated total",
+    ingredients_without_price: "ingredient(s) without price",
+    leftovers: "Leftovers",
+    to_finish_with: "use up with: ",
+    preparation: "Preparation",
+    cooking: "Cooking",
+    difficulty: "Difficulty",
+    tags: "Tags",
+    category: "Category",
+    season: "Season",
+    author: "Author",
+    source: "Source",
+    nutrition_sheet: "Nutrition",
+    nutrition_headers: [
+        "Day",
+        "Meal",
+        "Recipe",
+        "Persons",
+        "kcal / portion",
+        "Protein (g)",
+        "Carbs (g)",
+        "Fat (g)",
+        "Fibre (g)",
+        "Not counted",
+    ],
+    total: "Total",
+    incomplete_estimate: "Incomplete estimate",
+    daily_average: "Daily average",
+};
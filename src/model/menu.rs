@@ -1,7 +1,23 @@
 use crate::model::ingredient::Ingredient;
+use crate::model::nutrition::NutritionEstimate;
+use crate::model::package::PackagePlan;
+use crate::model::price::CostEstimate;
+use crate::model::recipe_slot::RecipeSlot;
+use crate::model::shopping_list::ShoppingItemSource;
 use crate::model::weekday::WeekDay;
+use std::collections::HashMap;
 
 pub struct Menu {
     pub all_ingredients: Vec<Ingredient>,
-    pub week_days: Vec<WeekDay>
-}
\ No newline at end of file
+    // Aligned with all_ingredients, the meals each ingredient is bought for
+    pub shopping_list_sources: Vec<Vec<ShoppingItemSource>>,
+    pub week_days: Vec<WeekDay>,
+    pub nutrition_estimates: HashMap<RecipeSlot, NutritionEstimate>,
+    // Aligned with all_ingredients
+    pub shopping_list_cost: CostEstimate,
+    pub recipe_costs: HashMap<RecipeSlot, CostEstimate>,
+    // Aligned with all_ingredients, None when the ingredient has no known package
+    pub package_plans: Vec<Option<PackagePlan>>,
+    // Leftovers of the packages, with recipes that could use them up
+    pub leftover_suggestions: Vec<(String, Vec<String>)>,
+}
@@ -0,0 +1,122 @@
+use crate::model::ingredient::Ingredient;
+use crate::model::language::Language;
+use crate::model::person_count::PersonCount;
+use serde::{Deserialize, Serialize};
+use std::ops::AddAssign;
+
+/// Nutrients of an amount of food, in kcal for the energy and grams for the rest.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct NutritionFacts {
+    pub kcal: f32,
+    pub protein: f32,
+    pub carbs: f32,
+    pub fat: f32,
+    pub fibre: f32,
+}
+
+impl NutritionFacts {
+    pub fn scaled(&self, factor: f32) -> NutritionFacts {
+        NutritionFacts {
+            kcal: self.kcal * factor,
+            protein: self.protein * factor,
+            carbs: self.carbs * factor,
+            fat: self.fat * factor,
+            fibre: self.fibre * factor,
+        }
+    }
+
+    /// One line summary such as "650 kcal · P 30 g · G 70 g · L 20 g · F 8 g".
+    pub fn summary(&self) -> String {
+        format!(
+            "{:.0} kcal · P {:.0} g · G {:.0} g · L {:.0} g · F {:.0} g",
+            self.kcal, self.protein, self.carbs, self.fat, self.fibre
+        )
+    }
+}
+
+impl AddAssign for NutritionFacts {
+    fn add_assign(&mut self, other: NutritionFacts) {
+        self.kcal += other.kcal;
+        self.protein += other.protein;
+        self.carbs += other.carbs;
+        self.fat += other.fat;
+        self.fibre += other.fibre;
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum NutritionBasis {
+    #[default]
+    #[serde(rename = "100g")]
+    Per100Grams,
+    #[serde(rename = "unite")]
+    PerUnit,
+}
+
+/// Nutrition values of an ingredient, as written in the ingredient catalogue.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NutritionEntry {
+    #[serde(flatten)]
+    pub facts: NutritionFacts,
+    #[serde(default)]
+    pub per: NutritionBasis,
+}
+
+impl NutritionEntry {
+    /// Nutrients of the ingredient, or None when its unit can't be converted to grams.
+    pub fn facts_of(&self, ingredient: &Ingredient, unit_weight: Option<f32>) -> Option<NutritionFacts> {
+        match self.per {
+            NutritionBasis::PerUnit if ingredient.is_counted() => Some(self.facts.scaled(ingredient.quantity)),
+            NutritionBasis::PerUnit => None,
+            NutritionBasis::Per100Grams => Some(self.facts.scaled(ingredient.grams(unit_weight)? / 100.0)),
+        }
+    }
+}
+
+/// Estimated nutrients of a recipe, along with the ingredients left out of the estimation.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NutritionEstimate {
+    pub total: NutritionFacts,
+    pub nbr_persons: PersonCount,
+    // Ingredients missing from the catalogue or without nutrition values
+    pub ingredients_without_data: Vec<String>,
+    // Ingredients whose unit can't be converted to grams, such as "1 pot crème"
+    pub ingredients_with_unknown_unit: Vec<String>,
+}
+
+impl NutritionEstimate {
+    pub fn per_portion(&self) -> NutritionFacts {
+        match self.nbr_persons == PersonCount::ZERO {
+            true => self.total,
+            false => self.total.scaled(1.0 / self.nbr_persons.as_f32()),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.ingredients_without_data.is_empty() && self.ingredients_with_unknown_unit.is_empty()
+    }
+
+    /// The ingredients left out, such as "sans données : sauce maison · unité inconvertible : 1 pot crème".
+    pub fn missing_summary(&self, language: Language) -> String {
+        let texts = language.texts();
+        let mut parts: Vec<String> = Vec::new();
+        if !self.ingredients_without_data.is_empty() {
+            parts.push(format!("{}{}", texts.without_nutrition_data, self.ingredients_without_data.join(", ")));
+        }
+        if !self.ingredients_with_unknown_unit.is_empty() {
+            parts.push(format!("{}{}", texts.unconvertible_unit, self.ingredients_with_unknown_unit.join(", ")));
+        }
+        parts.join(" · ")
+    }
+}
+
+/// What one person eats over several meals, and whether every meal could be fully estimated.
+pub fn per_person_total<'a>(nutrition_estimates: impl IntoIterator<Item = &'a NutritionEstimate>) -> (NutritionFacts, bool) {
+    let mut total = NutritionFacts::default();
+    let mut is_complete = true;
+    for nutrition_estimate in nutrition_estimates {
+        total += nutrition_estimate.per_portion();
+        is_complete &= nutrition_estimate.is_complete();
+    }
+    (total, is_complete)
+}
@@ -0,0 +1,171 @@
+use crate::model::ingredient::{Ingredient, WHOLE_INGREDIENT, round_smartly, unit_grams};
+use crate::model::language::Language;
+use serde::{Deserialize, Serialize};
+
+const PACKAGE_UNIT__PIECE: &str = "unite";
+// Margin avoiding to buy a whole package for a float rounding error
+const PACKAGE_TOLERANCE: f32 = 0.05;
+
+/// Size in which an ingredient is sold, as written in the ingredient catalogue :
+/// `{ size = 20, unit = "cl", name = "pot" }`, `{ size = 6, unit = "unite", name = "boîte" }`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Package {
+    pub size: f32,
+    pub unit: String,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Packages to buy to get a needed quantity, and what will be left once cooked.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PackagePlan {
+    pub package: Package,
+    pub nbr_packages: u32,
+    pub leftover: f32,
+}
+
+impl Package {
+    /// Quantity of the ingredient expressed in the unit of the package, or None when
+    /// the units can't be converted.
+    pub fn amount_of(&self, ingredient: &Ingredient, unit_weight: Option<f32>) -> Option<f32> {
+        let package_unit = self.unit.trim().to_lowercase();
+        if package_unit == PACKAGE_UNIT__PIECE {
+            return (ingredient.unit == WHOLE_INGREDIENT).then_some(ingredient.quantity);
+        }
+        if ingredient.unit != WHOLE_INGREDIENT && ingredient.unit_key() == package_unit {
+            return Some(ingredient.quantity);
+        }
+        Some(ingredient.grams(unit_weight)? / unit_grams(&package_unit)?)
+    }
+
+    pub fn plan_for(&self, ingredient: &Ingredient, unit_weight: Option<f32>) -> Option<PackagePlan> {
+        if self.size <= 0.0 {
+            return None;
+        }
+        let amount = self.amount_of(ingredient, unit_weight)?;
+        let nbr_packages = (amount / self.size - PACKAGE_TOLERANCE).ceil().max(1.0);
+        Some(PackagePlan {
+            package: self.clone(),
+            nbr_packages: nbr_packages as u32,
+            leftover: round_smartly((nbr_packages * self.size - amount).max(0.0)),
+        })
+    }
+
+    pub fn name(&self, language: Language) -> &str {
+        self.name.as_deref().unwrap_or(language.texts().default_package)
+    }
+
+    fn is_single_piece(&self) -> bool {
+        self.size == 1.0 && self.unit.trim().to_lowercase() == PACKAGE_UNIT__PIECE
+    }
+
+    fn format_amount(&self, amount: f32) -> String {
+        let formatted_amount = amount.to_string().replace('.', ",");
+        match self.unit.trim().to_lowercase().as_str() {
+            PACKAGE_UNIT__PIECE => formatted_amount,
+            // Units of measure stay as they are, the others take the plural : "4 tranches"
+            unit if amount > 1.0 && unit_grams(unit).is_none() && !unit.ends_with('s') => {
+                format!("{formatted_amount} {unit}s")
+            }
+            unit => format!("{formatted_amount} {unit}"),
+        }
+    }
+}
+
+/// Plan for the size leaving the least leftover, the fewest packages to break a tie.
+pub fn best_package_plan(packages: &[Package], ingredient: &Ingredient, unit_weight: Option<f32>) -> Option<PackagePlan> {
+    packages
+        .iter()
+        .filter_map(|package| package.plan_for(ingredient, unit_weight))
+        .min_by(|first, second| {
+            first
+                .leftover
+                .total_cmp(&second.leftover)
+                .then(first.nbr_packages.cmp(&second.nbr_packages))
+        })
+}
+
+impl PackagePlan {
+    pub fn has_leftover(&self) -> bool {
+        self.leftover > self.package.size * PACKAGE_TOLERANCE
+    }
+
+    /// "2 pots de 20 cl", "1 boîte de 6", "2 boules".
+    pub fn summary(&self, language: Language) -> String {
+        let package_name = self.package.name(language);
+        let plural_mark = if self.nbr_packages > 1 && !package_name.ends_with('s') { "s" } else { "" };
+        if self.package.is_single_piece() {
+            return format!("{} {package_name}{plural_mark}", self.nbr_packages);
+        }
+        format!(
+            "{} {package_name}{plural_mark}{}{}",
+            self.nbr_packages,
+            language.texts().package_of,
+            self.package.format_amount(self.package.size)
+        )
+    }
+
+    /// "5 cl", "2".
+    pub fn leftover_amount(&self) -> String {
+        self.package.format_amount(self.leftover)
+    }
+
+    /// "reste 5 cl", empty when nothing significant is left.
+    pub fn leftover_summary(&self, language: Language) -> String {
+        match self.has_leftover() {
+            true => format!("{}{}", language.texts().package_leftover, self.leftover_amount()),
+            false => String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(size: f32, unit: &str) -> Package {
+        Package { size, unit: unit.to_string(), name: Some("pot".to_string()) }
+    }
+
+    fn ingredient(quantity: f32, unit: &str) -> Ingredient {
+        Ingredient { name: "crème".to_string(), unit: unit.to_string(), quantity }
+    }
+
+    #[test]
+    fn an_exact_fit_leaves_nothing() {
+        let plan = package(20.0, "cl").plan_for(&ingredient(40.0, "cl"), None).unwrap();
+        assert_eq!(plan.nbr_packages, 2);
+        assert_eq!(plan.leftover, 0.0);
+        assert!(!plan.has_leftover());
+    }
+
+    #[test]
+    fn a_rounding_error_does_not_buy_another_package() {
+        let just_within = package(20.0, "cl").plan_for(&ingredient(20.9, "cl"), None).unwrap();
+        assert_eq!(just_within.nbr_packages, 1);
+        let just_beyond = package(20.0, "cl").plan_for(&ingredient(21.1, "cl"), None).unwrap();
+        assert_eq!(just_beyond.nbr_packages, 2);
+        assert!(just_beyond.has_leftover());
+    }
+
+    #[test]
+    fn converts_units_and_reports_the_leftover() {
+        let plan = package(20.0, "cl").plan_for(&ingredient(0.35, "l"), None).unwrap();
+        assert_eq!(plan.nbr_packages, 2);
+        assert_eq!(plan.leftover, 5.0);
+        assert!(plan.has_leftover());
+        assert!(package(20.0, "cl").plan_for(&ingredient(2.0, WHOLE_INGREDIENT), None).is_none());
+        assert!(package(0.0, "cl").plan_for(&ingredient(20.0, "cl"), None).is_none());
+    }
+
+    #[test]
+    fn chooses_the_size_leaving_the_least_leftover() {
+        let packages = [package(50.0, "cl"), package(20.0, "cl"), package(25.0, "cl")];
+        let plan = best_package_plan(&packages, &ingredient(50.0, "cl"), None).unwrap();
+        assert_eq!(plan.package.size, 50.0);
+        assert_eq!(plan.nbr_packages, 1);
+        let plan = best_package_plan(&packages, &ingredient(40.0, "cl"), None).unwrap();
+        assert_eq!(plan.package.size, 20.0);
+        assert_eq!(plan.nbr_packages, 2);
+    }
+}
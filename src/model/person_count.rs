@@ -0,0 +1,179 @@
+use crate::model::ingredient::parse_quantity;
+use crate::model::language::Language;
+
+pub const MAX_PERSONS: u8 = 99;
+
+/// Number of persons a recipe is cooked for, counted in halves and kept between 0 and
+/// `MAX_PERSONS` so that adding or removing persons can neither overflow nor go negative.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PersonCount {
+    halves: u16,
+}
+
+impl PersonCount {
+    pub const ZERO: PersonCount = PersonCount { halves: 0 };
+    pub const HALF: PersonCount = PersonCount { halves: 1 };
+    pub const ONE: PersonCount = PersonCount { halves: 2 };
+    pub const MAX: PersonCount = PersonCount { halves: MAX_PERSONS as u16 * 2 };
+
+    pub fn from_whole(persons: u8) -> PersonCount {
+        PersonCount { halves: persons.min(MAX_PERSONS) as u16 * 2 }
+    }
+
+    /// Validates a number of persons, which must be a whole or a half number within bounds.
+    pub fn new(persons: f32, language: Language) -> Result<PersonCount, String> {
+        let texts = language.texts();
+        let persons_text = persons.to_string().replace('.', texts.decimal_separator);
+        if !persons.is_finite() || persons < 0.0 {
+            return Err(format!("{persons_text} {}", texts.not_a_nbr_persons));
+        }
+        if persons > MAX_PERSONS as f32 {
+            return Err(format!("{}{MAX_PERSONS}", texts.at_most_persons));
+        }
+        let halves = persons * 2.0;
+        if halves.fract() != 0.0 {
+            return Err(format!("{persons_text} {}", texts.neither_whole_nor_half));
+        }
+        Ok(PersonCount { halves: halves as u16 })
+    }
+
+    /// Rounds a number of portions up to the next whole person, or the next half person
+    /// when half portions are allowed, within bounds.
+    pub fn rounded_up(portions: f32, allow_half_portions: bool) -> PersonCount {
+        if !portions.is_finite() || portions <= 0.0 {
+            return PersonCount::ZERO;
+        }
+        let halves = match allow_half_portions {
+            true => (portions * 2.0).ceil(),
+            false => portions.ceil() * 2.0,
+        };
+        PersonCount { halves: halves.min(PersonCount::MAX.halves as f32) as u16 }
+    }
+
+    /// Parses a number of persons typed by the user : "3", "2.5", "2,5", "2 ½".
+    pub fn parse(text: &str, language: Language) -> Result<PersonCount, String> {
+        match parse_quantity(text) {
+            Some(persons) => PersonCount::new(persons, language),
+            None => Err(format!("« {} » {}", text.trim(), language.texts().not_a_number)),
+        }
+    }
+
+    pub fn as_f32(&self) -> f32 {
+        self.halves as f32 / 2.0
+    }
+
+    pub fn saturating_add(self, other: PersonCount) -> PersonCount {
+        PersonCount { halves: (self.halves + other.halves).min(PersonCount::MAX.halves) }
+    }
+
+    pub fn saturating_sub(self, other: PersonCount) -> PersonCount {
+        PersonCount { halves: self.halves.saturating_sub(other.halves) }
+    }
+
+    /// Drops the half person, if any.
+    pub fn ceil(self) -> PersonCount {
+        PersonCount { halves: self.halves.div_ceil(2) * 2 }
+    }
+
+    /// Factor to apply to quantities written for `recipe_nbr_persons`, a recipe written
+    /// for nobody being left as is rather than divided by zero.
+    pub fn scaling_factor(&self, recipe_nbr_persons: u8) -> f32 {
+        match recipe_nbr_persons {
+            0 => 1.0,
+            recipe_nbr_persons => self.as_f32() / recipe_nbr_persons as f32,
+        }
+    }
+
+    /// Number of persons written with the decimal separator of the language : "2,5" or "2.5".
+    pub fn label(&self, language: Language) -> String {
+        match self.halves % 2 {
+            0 => format!("{}", self.halves / 2),
+            _ => format!("{}{}5", self.halves / 2, language.texts().decimal_separator),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ingredient::{Ingredient, WHOLE_INGREDIENT};
+    use crate::model::recipe::Recipe;
+
+    #[test]
+    fn decrementing_zero_stays_at_zero() {
+        assert_eq!(PersonCount::ZERO.saturating_sub(PersonCount::ONE), PersonCount::ZERO);
+        assert_eq!(PersonCount::HALF.saturating_sub(PersonCount::ONE), PersonCount::ZERO);
+    }
+
+    #[test]
+    fn incrementing_stops_at_the_maximum() {
+        assert_eq!(PersonCount::MAX.saturating_add(PersonCount::ONE), PersonCount::MAX);
+        assert_eq!(PersonCount::from_whole(u8::MAX), PersonCount::MAX);
+    }
+
+    #[test]
+    fn new_rejects_invalid_counts() {
+        assert!(PersonCount::new(-1.0, Language::French).is_err());
+        assert!(PersonCount::new(f32::NAN, Language::French).is_err());
+        assert!(PersonCount::new(f32::INFINITY, Language::French).is_err());
+        assert!(PersonCount::new(MAX_PERSONS as f32 + 1.0, Language::French).is_err());
+        assert!(PersonCount::new(2.3, Language::French).is_err());
+        assert_eq!(PersonCount::new(2.5, Language::French).unwrap().as_f32(), 2.5);
+    }
+
+    #[test]
+    fn parse_accepts_halves_written_in_any_way() {
+        for text in ["2.5", "2,5", "2 ½", "5/2", " 2.5 "] {
+            assert_eq!(PersonCount::parse(text, Language::French), PersonCount::new(2.5, Language::French), "{text}");
+        }
+        assert_eq!(PersonCount::parse("4", Language::French), Ok(PersonCount::from_whole(4)));
+        assert!(PersonCount::parse("", Language::French).is_err());
+        assert!(PersonCount::parse("quatre", Language::French).is_err());
+        assert!(PersonCount::parse("-2", Language::French).is_err());
+    }
+
+    #[test]
+    fn rounded_up_respects_half_portions() {
+        assert_eq!(PersonCount::rounded_up(2.3, false), PersonCount::from_whole(3));
+        assert_eq!(PersonCount::rounded_up(2.3, true), PersonCount::new(2.5, Language::French).unwrap());
+        assert_eq!(PersonCount::rounded_up(-1.0, true), PersonCount::ZERO);
+        assert_eq!(PersonCount::rounded_up(1000.0, false), PersonCount::MAX);
+    }
+
+    #[test]
+    fn label_uses_the_decimal_separator_of_the_language() {
+        assert_eq!(PersonCount::from_whole(3).label(Language::English), "3");
+        assert_eq!(PersonCount::HALF.saturating_add(PersonCount::ONE).label(Language::French), "1,5");
+        assert_eq!(PersonCount::HALF.saturating_add(PersonCount::ONE).label(Language::English), "1.5");
+    }
+
+    #[test]
+    fn errors_are_in_the_language_of_the_interface() {
+        assert_eq!(PersonCount::parse("quatre", Language::English), Err("« quatre » is not a number".to_string()));
+        assert_eq!(PersonCount::new(2.3, Language::English), Err("2.3 is neither a whole number nor a half".to_string()));
+        assert_eq!(PersonCount::new(2.3, Language::French), Err("2,3 n'est ni un nombre entier ni une demie".to_string()));
+    }
+
+    #[test]
+    fn scaling_a_recipe_written_for_nobody_keeps_its_quantities() {
+        let mut recipe = Recipe::new();
+        recipe.nbr_persons = 0;
+        recipe.configured_nbr_persons = PersonCount::from_whole(4);
+        recipe.add_ingredient(Ingredient { name: "farine".to_string(), unit: "g".to_string(), quantity: 200.0 });
+        recipe.sync_with_configured_nbr_persons();
+        assert_eq!(recipe.ingredients[0].quantity, 200.0);
+    }
+
+    #[test]
+    fn scaling_a_recipe_to_nobody_needs_nothing() {
+        let mut recipe = Recipe::new();
+        recipe.nbr_persons = 4;
+        recipe.configured_nbr_persons = PersonCount::ZERO;
+        recipe.add_ingredient(Ingredient { name: "oignon".to_string(), unit: WHOLE_INGREDIENT.to_string(), quantity: 2.0 });
+        recipe.add_ingredient(Ingredient { name: "crème".to_string(), unit: "pot".to_string(), quantity: 1.0 });
+        recipe.add_ingredient(Ingredient { name: "sel".to_string(), unit: "pincée".to_string(), quantity: 1.0 });
+        recipe.add_ingredient(Ingredient { name: "farine".to_string(), unit: "g".to_string(), quantity: 200.0 });
+        recipe.sync_with_configured_nbr_persons();
+        assert!(recipe.ingredients.iter().all(|ingredient| ingredient.quantity == 0.0), "{:?}", recipe.ingredients);
+    }
+}
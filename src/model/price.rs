@@ -0,0 +1,60 @@
+use crate::model::ingredient::{Ingredient, WHOLE_INGREDIENT};
+use crate::model::language::Language;
+use crate::model::person_count::PersonCount;
+use serde::{Deserialize, Serialize};
+
+const PRICE_UNIT__KILOGRAM: &str = "kg";
+const PRICE_UNIT__LITRE: &str = "l";
+const PRICE_UNIT__PIECE: &str = "unite";
+
+/// Price in euros of an ingredient, as written in the ingredient catalogue : per kilogram,
+/// per litre, per piece ("unite") or per any unit used in the recipes ("pot", "sachet"...).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Price {
+    pub price: f32,
+    pub per: String,
+}
+
+impl Price {
+    /// Cost of the ingredient, or None when this price doesn't apply to its unit.
+    pub fn cost_of(&self, ingredient: &Ingredient, unit_weight: Option<f32>) -> Option<f32> {
+        match self.per.trim().to_lowercase().as_str() {
+            PRICE_UNIT__KILOGRAM | PRICE_UNIT__LITRE => Some(ingredient.grams(unit_weight)? / 1000.0 * self.price),
+            PRICE_UNIT__PIECE if ingredient.unit == WHOLE_INGREDIENT => Some(ingredient.quantity * self.price),
+            per if ingredient.unit != WHOLE_INGREDIENT && ingredient.unit_key() == per => {
+                Some(ingredient.quantity * self.price)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Estimated cost of a list of ingredients, line by line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CostEstimate {
+    // One cost per ingredient, None when no price applies
+    pub line_costs: Vec<Option<f32>>,
+    pub ingredients_without_price: Vec<String>,
+}
+
+impl CostEstimate {
+    pub fn total(&self) -> f32 {
+        self.line_costs.iter().flatten().sum()
+    }
+
+    pub fn per_portion(&self, nbr_persons: PersonCount) -> f32 {
+        match nbr_persons == PersonCount::ZERO {
+            true => self.total(),
+            false => self.total() / nbr_persons.as_f32(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.ingredients_without_price.is_empty()
+    }
+}
+
+/// Formats an amount of euros with the decimal separator of the language : "12,40 €" or "12.40 €".
+pub fn format_price(price: f32, language: Language) -> String {
+    format!("{price:.2} €").replace('.', language.texts().decimal_separator)
+}
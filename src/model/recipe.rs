@@ -1,22 +1,17 @@
 use crate::model::ingredient::Ingredient;
+use crate::model::person_count::PersonCount;
+use crate::model::recipe_metadata::RecipeMetadata;
 
 
-pub const NOON: &str = "Midi";
-pub const EVENING: &str = "Soir";
-
-
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Recipe {
     pub name: String,
     pub nbr_persons: u8,
-    pub configured_nbr_persons: u8,
+    pub configured_nbr_persons: PersonCount,
     pub is_veggie: bool,
     pub ingredients: Vec<Ingredient>,
     pub steps: Vec<String>,
-}
-
-fn round_to_2_digits(num: f32) -> f32 {
-    (num * 100.0).round() / 100.0
+    pub metadata: RecipeMetadata,
 }
 
 impl Recipe {
@@ -24,10 +19,11 @@ impl Recipe {
         Recipe {
             name: "".to_string(),
             nbr_persons: 1,
-            configured_nbr_persons: 1,
+            configured_nbr_persons: PersonCount::ONE,
             is_veggie: false,
             ingredients: Vec::new(),
             steps: Vec::new(),
+            metadata: RecipeMetadata::default(),
         }
     }
 
@@ -41,11 +37,14 @@ impl Recipe {
         self.steps.push(step);
     }
 
+    pub fn reset_configured_nbr_persons(&mut self) {
+        self.configured_nbr_persons = PersonCount::from_whole(self.nbr_persons);
+    }
+
     pub fn sync_with_configured_nbr_persons(&mut self) {
+        let scaling_factor = self.configured_nbr_persons.scaling_factor(self.nbr_persons);
         for ingredient in self.ingredients.iter_mut() {
-            let quantity_for_one_person = ingredient.quantity / self.nbr_persons as f32;
-            let new_quantity = quantity_for_one_person * self.configured_nbr_persons as f32;
-            ingredient.quantity = round_to_2_digits(new_quantity);
+            ingredient.quantity = ingredient.scaled_quantity(scaling_factor);
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,120 @@
+use crate::model::ingredient::{Ingredient, WHOLE_INGREDIENT, parse_quantity};
+use crate::model::recipe::Recipe;
+use crate::model::recipe_metadata::{Difficulty, RecipeMetadata};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Layout of a recipe saved in the structured `.toml` format.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecipeFile {
+    pub name: String,
+    pub nbr_persons: u8,
+    #[serde(default)]
+    pub veggie: bool,
+    // Times in minutes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preparation_time: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooking_time: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<Difficulty>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub season: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub ingredients: Vec<IngredientLine>,
+    #[serde(default)]
+    pub steps: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct IngredientLine {
+    // Either a number or a fraction written as a string : "1/2", "½"
+    #[serde(deserialize_with = "deserialize_quantity")]
+    pub quantity: f32,
+    // Absent for ingredients counted as a whole ("2 oignons")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    pub name: String,
+}
+
+fn deserialize_quantity<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Quantity {
+        Number(f32),
+        Text(String),
+    }
+
+    match Quantity::deserialize(deserializer)? {
+        Quantity::Number(quantity) => Ok(quantity),
+        Quantity::Text(text) => {
+            parse_quantity(&text).ok_or_else(|| serde::de::Error::custom(format!("invalid quantity \"{text}\"")))
+        }
+    }
+}
+
+impl From<&Recipe> for RecipeFile {
+    fn from(recipe: &Recipe) -> Self {
+        RecipeFile {
+            name: recipe.name.clone(),
+            nbr_persons: recipe.nbr_persons,
+            veggie: recipe.is_veggie,
+            preparation_time: recipe.metadata.preparation_time,
+            cooking_time: recipe.metadata.cooking_time,
+            difficulty: recipe.metadata.difficulty,
+            tags: recipe.metadata.tags.clone(),
+            category: recipe.metadata.category.clone(),
+            season: recipe.metadata.season.clone(),
+            source: recipe.metadata.source.clone(),
+            author: recipe.metadata.author.clone(),
+            ingredients: recipe
+                .ingredients
+                .iter()
+                .map(|ingredient| IngredientLine {
+                    quantity: ingredient.quantity,
+                    unit: (ingredient.unit != WHOLE_INGREDIENT).then(|| ingredient.unit.clone()),
+                    name: ingredient.name.clone(),
+                })
+                .collect(),
+            steps: recipe.steps.clone(),
+        }
+    }
+}
+
+impl RecipeFile {
+    pub fn into_recipe(self) -> Recipe {
+        let mut recipe = Recipe::new();
+        recipe.set_name(self.name);
+        recipe.nbr_persons = self.nbr_persons;
+        recipe.reset_configured_nbr_persons();
+        recipe.is_veggie = self.veggie;
+        recipe.metadata = RecipeMetadata {
+            preparation_time: self.preparation_time,
+            cooking_time: self.cooking_time,
+            difficulty: self.difficulty,
+            tags: self.tags,
+            category: self.category,
+            season: self.season,
+            source: self.source,
+            author: self.author,
+        };
+        for ingredient in self.ingredients {
+            recipe.add_ingredient(Ingredient {
+                name: ingredient.name,
+                unit: ingredient.unit.unwrap_or(WHOLE_INGREDIENT.to_string()),
+                quantity: ingredient.quantity,
+            });
+        }
+        for step in self.steps {
+            recipe.add_step(step);
+        }
+        recipe
+    }
+}
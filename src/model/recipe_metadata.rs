@@ -0,0 +1,116 @@
+use crate::model::language::Language;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
+
+    pub fn parse(text: &str) -> Option<Difficulty> {
+        match text.trim().to_lowercase().as_str() {
+            "facile" | "easy" | "très facile" => Some(Difficulty::Easy),
+            "moyen" | "moyenne" | "medium" | "intermédiaire" => Some(Difficulty::Medium),
+            "difficile" | "hard" | "difficult" => Some(Difficulty::Hard),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self, language: Language) -> &'static str {
+        language.texts().difficulties[Difficulty::ALL.iter().position(|difficulty| difficulty == self).unwrap()]
+    }
+}
+
+/// Optional information about a recipe, times being expressed in minutes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecipeMetadata {
+    pub preparation_time: Option<u16>,
+    pub cooking_time: Option<u16>,
+    pub difficulty: Option<Difficulty>,
+    pub tags: Vec<String>,
+    // Kind of dish : "entrée", "plat", "dessert", ...
+    pub category: Option<String>,
+    pub season: Option<String>,
+    pub source: Option<String>,
+    pub author: Option<String>,
+}
+
+impl RecipeMetadata {
+    /// One line summary such as "Préparation 15 min · Cuisson 30 min · Facile · #four".
+    pub fn summary(&self, language: Language) -> String {
+        let texts = language.texts();
+        let mut parts: Vec<String> = Vec::new();
+        if let Some(preparation_time) = self.preparation_time {
+            parts.push(format!("{} {}", texts.preparation, format_duration(preparation_time)));
+        }
+        if let Some(cooking_time) = self.cooking_time {
+            parts.push(format!("{} {}", texts.cooking, format_duration(cooking_time)));
+        }
+        if let Some(difficulty) = self.difficulty {
+            parts.push(difficulty.label(language).to_string());
+        }
+        if let Some(category) = &self.category {
+            parts.push(category.clone());
+        }
+        if let Some(season) = &self.season {
+            parts.push(season.clone());
+        }
+        if !self.tags.is_empty() {
+            parts.push(self.tags.iter().map(|tag| format!("#{tag}")).collect::<Vec<String>>().join(" "));
+        }
+        if let Some(author) = &self.author {
+            parts.push(format!("{}{author}", texts.by_author));
+        }
+        parts.join(" · ")
+    }
+}
+
+pub fn format_duration(minutes: u16) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{minutes} min"),
+        (hours, 0) => format!("{hours} h"),
+        (hours, minutes) => format!("{hours} h {minutes:02}"),
+    }
+}
+
+/// Parses a duration written as "45", "45 min", "1h30", "1 h 30" or "2h".
+pub fn parse_duration(text: &str) -> Option<u16> {
+    let text: String = text.to_lowercase().chars().filter(|character| !character.is_whitespace()).collect();
+    let text = text.trim_end_matches("minutes").trim_end_matches("min").trim_end_matches("mn");
+    match text.split_once('h') {
+        Some((hours, minutes)) => {
+            let hours = hours.parse::<u16>().ok()?;
+            let minutes = match minutes {
+                "" => 0,
+                minutes => minutes.parse::<u16>().ok()?,
+            };
+            hours.checked_mul(60)?.checked_add(minutes)
+        }
+        None => text.parse::<u16>().ok(),
+    }
+}
+
+/// Parses an ISO 8601 duration as found in schema.org recipes ("PT1H30M").
+pub fn parse_iso_8601_duration(text: &str) -> Option<u16> {
+    let time_part = text.trim().strip_prefix("PT").or(text.trim().strip_prefix("P0DT"))?;
+    let mut minutes: u16 = 0;
+    let mut number = String::new();
+    for character in time_part.chars() {
+        match character {
+            '0'..='9' => number.push(character),
+            'H' => {
+                let hours = std::mem::take(&mut number).parse::<u16>().ok()?;
+                minutes = minutes.checked_add(hours.checked_mul(60)?)?;
+            }
+            'M' => minutes = minutes.checked_add(std::mem::take(&mut number).parse::<u16>().ok()?)?,
+            'S' => number.clear(),
+            _ => return None,
+        }
+    }
+    Some(minutes)
+}
@@ -0,0 +1,141 @@
+use crate::model::language::Language;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Day {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Day {
+    pub const ALL: [Day; 7] = [
+        Day::Monday,
+        Day::Tuesday,
+        Day::Wednesday,
+        Day::Thursday,
+        Day::Friday,
+        Day::Saturday,
+        Day::Sunday,
+    ];
+
+    pub fn name(&self, language: Language) -> &'static str {
+        language.texts().days[self.offset() as usize]
+    }
+
+    /// Finds the day named in any language, so that a workbook can be read whatever its language.
+    pub fn parse(text: &str) -> Option<Day> {
+        Language::ALL.iter().find_map(|language| {
+            let position = language.texts().days.iter().position(|day| day.eq_ignore_ascii_case(text.trim()))?;
+            Some(Day::ALL[position])
+        })
+    }
+
+    /// Days since the Monday starting the week.
+    pub fn offset(&self) -> i64 {
+        Day::ALL.iter().position(|day| day == self).unwrap() as i64
+    }
+
+    pub fn slot(&self, meal: Meal) -> RecipeSlot {
+        RecipeSlot { day: *self, meal }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Meal {
+    Noon,
+    Evening,
+}
+
+impl Meal {
+    pub const ALL: [Meal; 2] = [Meal::Noon, Meal::Evening];
+
+    pub fn position(&self) -> usize {
+        Meal::ALL.iter().position(|meal| meal == self).unwrap()
+    }
+
+    pub fn name(&self, language: Language) -> &'static str {
+        language.texts().meals[self.position()]
+    }
+
+    /// Finds the meal named in any language.
+    pub fn parse(text: &str) -> Option<Meal> {
+        Language::ALL.iter().find_map(|language| {
+            let position = language.texts().meals.iter().position(|meal| meal.eq_ignore_ascii_case(text.trim()))?;
+            Some(Meal::ALL[position])
+        })
+    }
+
+    /// The slots of this meal, from Monday to Sunday.
+    pub fn slots(self) -> impl Iterator<Item = RecipeSlot> {
+        Day::ALL.into_iter().map(move |day| day.slot(self))
+    }
+}
+
+/// A meal of the week a recipe can be planned for.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct RecipeSlot {
+    pub day: Day,
+    pub meal: Meal,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::weekday::WeekDay;
+    use std::collections::HashSet;
+
+    fn all_slots() -> impl Iterator<Item = RecipeSlot> {
+        Meal::ALL.into_iter().flat_map(Meal::slots)
+    }
+
+    #[test]
+    fn the_week_has_fourteen_distinct_slots() {
+        let slots: HashSet<RecipeSlot> = all_slots().collect();
+        assert_eq!(slots.len(), Day::ALL.len() * Meal::ALL.len());
+    }
+
+    #[test]
+    fn every_slot_maps_to_exactly_one_day() {
+        let week_days: Vec<WeekDay> = Day::ALL.into_iter().map(WeekDay::new).collect();
+        for slot in all_slots() {
+            let days_of_slot: Vec<&WeekDay> = week_days
+                .iter()
+                .filter(|week_day| week_day.noon_recipe_slot == slot || week_day.evening_recipe_slot == slot)
+                .collect();
+            assert_eq!(days_of_slot.len(), 1, "{slot:?}");
+            assert_eq!(days_of_slot[0].day, slot.day);
+        }
+    }
+
+    #[test]
+    fn sunday_noon_belongs_to_sunday() {
+        let sunday = WeekDay::new(Day::Sunday);
+        assert_eq!(sunday.noon_recipe_slot, RecipeSlot { day: Day::Sunday, meal: Meal::Noon });
+        assert_eq!(sunday.evening_recipe_slot, RecipeSlot { day: Day::Sunday, meal: Meal::Evening });
+    }
+
+    #[test]
+    fn meal_slots_follow_the_week() {
+        let noon_days: Vec<Day> = Meal::Noon.slots().map(|slot| slot.day).collect();
+        assert_eq!(noon_days, Day::ALL);
+        assert!(Meal::Evening.slots().all(|slot| slot.meal == Meal::Evening));
+    }
+
+    #[test]
+    fn days_and_meals_are_read_in_any_language() {
+        for language in Language::ALL {
+            for day in Day::ALL {
+                assert_eq!(Day::parse(day.name(language)), Some(day));
+            }
+            for meal in Meal::ALL {
+                assert_eq!(Meal::parse(meal.name(language)), Some(meal));
+            }
+        }
+        assert_eq!(Day::parse("Dimanche"), Day::parse("Sunday"));
+        assert_eq!(Day::parse("Funday"), None);
+    }
+}
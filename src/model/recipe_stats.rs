@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+pub const MIN_RATING_STARS: u8 = 1;
+pub const MAX_RATING_STARS: u8 = 5;
+
+/// What we know about the use of a recipe, stored locally and keyed by recipe name.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RecipeStats {
+    #[serde(default)]
+    pub times_planned: u32,
+    // Seconds since UNIX epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_planned: Option<u64>,
+    #[serde(default)]
+    pub is_favourite: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ratings: Vec<RecipeRating>,
+}
+
+/// The opinion given on a recipe after having cooked it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecipeRating {
+    pub stars: u8,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub note: String,
+    // Seconds since UNIX epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rated_at: Option<u64>,
+}
+
+impl RecipeStats {
+    pub fn average_rating(&self) -> Option<f32> {
+        if self.ratings.is_empty() {
+            return None;
+        }
+        let total_stars: u32 = self.ratings.iter().map(|rating| rating.stars as u32).sum();
+        Some(total_stars as f32 / self.ratings.len() as f32)
+    }
+
+    pub fn last_rating(&self) -> Option<&RecipeRating> {
+        self.ratings.last()
+    }
+}
@@ -0,0 +1,112 @@
+use crate::model::language::{Language, Texts};
+use crate::model::person_count::MAX_PERSONS;
+use crate::model::recipe_slot::Meal;
+use crate::model::theme::ThemeMode;
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_RECIPES_DIRECTORY: &str = "recipes/";
+pub const DEFAULT_OUTPUT_FILE: &str = "menu.xlsx";
+pub const DEFAULT_HEADER_COLOUR: u32 = 0x32c1eb;
+pub const DEFAULT_NOON_TIME: &str = "12:00";
+pub const DEFAULT_EVENING_TIME: &str = "19:30";
+
+/// Preferences of the user, saved in the configuration file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub recipes_directory: String,
+    pub output_file: String,
+    // Background colour of the headers of the exported workbook, as "#rrggbb"
+    pub header_colour: String,
+    // Persons a recipe is planned for when there is no household, its own count if None
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_nbr_persons: Option<u8>,
+    pub language: Language,
+    pub noon_time: String,
+    pub evening_time: String,
+    pub theme: ThemeMode,
+    // Colour of the buttons and highlights as "#rrggbb", the theme's own if empty
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub accent_colour: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            recipes_directory: DEFAULT_RECIPES_DIRECTORY.to_string(),
+            output_file: DEFAULT_OUTPUT_FILE.to_string(),
+            header_colour: format!("#{DEFAULT_HEADER_COLOUR:06x}"),
+            default_nbr_persons: None,
+            language: Language::from_locale(),
+            noon_time: DEFAULT_NOON_TIME.to_string(),
+            evening_time: DEFAULT_EVENING_TIME.to_string(),
+            theme: ThemeMode::default(),
+            accent_colour: String::new(),
+        }
+    }
+}
+
+impl Settings {
+    pub fn header_colour_rgb(&self) -> u32 {
+        parse_colour(&self.header_colour, self.language.texts()).unwrap_or(DEFAULT_HEADER_COLOUR)
+    }
+
+    pub fn accent_colour_rgb(&self) -> Option<u32> {
+        parse_colour(&self.accent_colour, self.language.texts()).ok()
+    }
+
+    pub fn meal_time(&self, meal: Meal) -> &str {
+        match meal {
+            Meal::Noon => &self.noon_time,
+            Meal::Evening => &self.evening_time,
+        }
+    }
+
+    /// Checks the values typed in the settings view, the errors being written in `language`.
+    pub fn validate(&self, language: Language) -> Result<(), String> {
+        let texts = language.texts();
+        if self.recipes_directory.trim().is_empty() {
+            return Err(texts.recipes_directory_required.to_string());
+        }
+        if self.output_file.trim().is_empty() {
+            return Err(texts.output_file_required.to_string());
+        }
+        parse_colour(&self.header_colour, texts)?;
+        if let Some(default_nbr_persons) = self.default_nbr_persons
+            && (default_nbr_persons == 0 || default_nbr_persons > MAX_PERSONS)
+        {
+            return Err(format!("{}{MAX_PERSONS}", texts.nbr_persons_between));
+        }
+        parse_meal_time(&self.noon_time, texts)?;
+        parse_meal_time(&self.evening_time, texts)?;
+        if !self.accent_colour.trim().is_empty() {
+            parse_colour(&self.accent_colour, texts)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a colour written "#32c1eb" or "32c1eb".
+pub fn parse_colour(text: &str, texts: &Texts) -> Result<u32, String> {
+    let error = || format!("« {text} » {}", texts.not_a_colour);
+    let hexadecimal = text.trim().trim_start_matches('#');
+    match hexadecimal.len() == 6 {
+        true => u32::from_str_radix(hexadecimal, 16).map_err(|_| error()),
+        false => Err(error()),
+    }
+}
+
+/// Parses a time written "12:00" or "19h30" into hours and minutes.
+pub fn parse_meal_time(text: &str, texts: &Texts) -> Result<(u8, u8), String> {
+    let error = || format!("« {text} » {}", texts.not_a_time);
+    let (hours, minutes) = text.trim().split_once([':', 'h']).ok_or_else(error)?;
+    let hours = hours.parse::<u8>().map_err(|_| error())?;
+    let minutes = match minutes {
+        "" => 0,
+        minutes => minutes.parse::<u8>().map_err(|_| error())?,
+    };
+    match hours < 24 && minutes < 60 {
+        true => Ok((hours, minutes)),
+        false => Err(error()),
+    }
+}
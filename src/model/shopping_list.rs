@@ -0,0 +1,97 @@
+use crate::model::ingredient::{Ingredient, format_quantity};
+use crate::model::language::Language;
+use crate::model::recipe_slot::RecipeSlot;
+use std::collections::{HashMap, HashSet};
+
+/// Identifies a line of the shopping list, an ingredient being listed once per unit.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ShoppingItemKey {
+    pub name: String,
+    pub unit: String,
+}
+
+impl ShoppingItemKey {
+    pub fn of(ingredient: &Ingredient) -> ShoppingItemKey {
+        ShoppingItemKey {
+            name: ingredient.name.clone(),
+            unit: ingredient.unit.clone(),
+        }
+    }
+}
+
+/// What a line of the shopping list is needed for, and how much of it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShoppingItemSource {
+    Recipe { recipe_slot: RecipeSlot, recipe_name: String, quantity: f32 },
+    Manual { quantity: f32 },
+}
+
+impl ShoppingItemSource {
+    pub fn quantity(&self) -> f32 {
+        match self {
+            ShoppingItemSource::Recipe { quantity, .. } | ShoppingItemSource::Manual { quantity } => *quantity,
+        }
+    }
+
+    pub fn is_manual(&self) -> bool {
+        matches!(self, ShoppingItemSource::Manual { .. })
+    }
+
+    /// "Lundi midi", or what says the item was added by hand.
+    pub fn meal(&self, language: Language) -> String {
+        match self {
+            ShoppingItemSource::Recipe { recipe_slot, .. } => format!(
+                "{} {}",
+                recipe_slot.day.name(language),
+                recipe_slot.meal.name(language).to_lowercase()
+            ),
+            ShoppingItemSource::Manual { .. } => language.texts().added_by_hand.to_string(),
+        }
+    }
+
+    /// "Lundi midi · Dahl : 200 g".
+    pub fn breakdown(&self, unit: &str, language: Language) -> String {
+        let quantity = format_quantity(self.quantity(), unit);
+        match self {
+            ShoppingItemSource::Recipe { recipe_name, .. } => {
+                format!("{} · {recipe_name} : {quantity}", self.meal(language))
+            }
+            ShoppingItemSource::Manual { .. } => format!("{} : {quantity}", self.meal(language)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShoppingListLine {
+    // Quantity to buy, once the edits applied
+    pub ingredient: Ingredient,
+    pub sources: Vec<ShoppingItemSource>,
+    // Already at home, so left out of what is bought
+    pub is_ticked: bool,
+    pub is_edited: bool,
+}
+
+impl ShoppingListLine {
+    pub fn key(&self) -> ShoppingItemKey {
+        ShoppingItemKey::of(&self.ingredient)
+    }
+
+    pub fn is_manual_only(&self) -> bool {
+        self.sources.iter().all(ShoppingItemSource::is_manual)
+    }
+}
+
+/// Changes made by hand to the shopping list computed from the planned recipes, kept while
+/// the plan changes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShoppingListEdits {
+    pub ticked_items: HashSet<ShoppingItemKey>,
+    pub edited_quantities: HashMap<ShoppingItemKey, f32>,
+    pub manual_items: Vec<Ingredient>,
+}
+
+impl ShoppingListEdits {
+    pub fn is_empty(&self) -> bool {
+        self.ticked_items.is_empty() && self.edited_quantities.is_empty() && self.manual_items.is_empty()
+    }
+}
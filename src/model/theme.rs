@@ -0,0 +1,20 @@
+use crate::model::language::Language;
+use serde::{Deserialize, Serialize};
+
+/// Appearance of the application, following the light or dark mode of the system by default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeMode {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl ThemeMode {
+    pub const ALL: [ThemeMode; 3] = [ThemeMode::System, ThemeMode::Light, ThemeMode::Dark];
+
+    pub fn label(&self, language: Language) -> &'static str {
+        language.texts().theme_modes[ThemeMode::ALL.iter().position(|theme_mode| theme_mode == self).unwrap()]
+    }
+}
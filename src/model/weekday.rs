@@ -1,18 +1,9 @@
-use crate::controller::main_controller::RecipeSlot;
 use crate::model::recipe::Recipe;
-
-pub const MONDAY: &str = "Lundi";
-pub const TUESDAY: &str = "Mardi";
-pub const WEDNESDAY: &str = "Mercredi";
-pub const THURSDAY: &str = "Jeudi";
-pub const FRIDAY: &str = "Vendredi";
-pub const SATURDAY: &str = "Samedi";
-pub const SUNDAY: &str = "Dimanche";
+use crate::model::recipe_slot::{Day, Meal, RecipeSlot};
 
 #[derive(Clone, Debug)]
 pub struct WeekDay {
-    pub day_position: i8,
-    pub name: String,
+    pub day: Day,
     pub noon_recipe_slot: RecipeSlot,
     pub evening_recipe_slot: RecipeSlot,
     pub noon_recipe: Option<Recipe>,
@@ -20,19 +11,20 @@ pub struct WeekDay {
 }
 
 impl WeekDay {
-    pub fn new(
-        name: String,
-        day_position: i8,
-        noon_recipe_slot: RecipeSlot,
-        evening_recipe_slot: RecipeSlot,
-    ) -> WeekDay {
+    pub fn new(day: Day) -> WeekDay {
         WeekDay {
-            name,
-            day_position,
-            noon_recipe_slot,
-            evening_recipe_slot,
+            day,
+            noon_recipe_slot: day.slot(Meal::Noon),
+            evening_recipe_slot: day.slot(Meal::Evening),
             noon_recipe: None,
             evening_recipe: None,
         }
     }
+
+    pub fn recipe_of(&self, meal: Meal) -> Option<&Recipe> {
+        match meal {
+            Meal::Noon => self.noon_recipe.as_ref(),
+            Meal::Evening => self.evening_recipe.as_ref(),
+        }
+    }
 }
@@ -1 +1,5 @@
-pub mod recipe_repository;
\ No newline at end of file
+pub mod recipe_repository;
+pub mod recipe_stats_repository;
+pub mod ingredient_catalogue_repository;
+pub mod household_repository;
+pub mod settings_repository;
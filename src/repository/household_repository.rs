@@ -0,0 +1,27 @@
+use crate::model::household::{Household, HouseholdMember};
+
+const HOUSEHOLD_FILE: &str = "data/household.toml";
+
+#[derive(Clone)]
+pub struct HouseholdRepository {
+    household: Household,
+}
+
+impl HouseholdRepository {
+    /// Loads the household file, an absent file giving a household without any member.
+    pub fn load() -> Result<HouseholdRepository, String> {
+        let household = match std::fs::read_to_string(HOUSEHOLD_FILE) {
+            Ok(content) => toml::from_str(&content).map_err(|error| format!("Failed to load {HOUSEHOLD_FILE} : {error}"))?,
+            Err(_) => Household::default(),
+        };
+        Ok(HouseholdRepository { household })
+    }
+
+    pub fn empty() -> HouseholdRepository {
+        HouseholdRepository { household: Household::default() }
+    }
+
+    pub fn list_all_members(&self) -> &[HouseholdMember] {
+        &self.household.members
+    }
+}
@@ -0,0 +1,34 @@
+use crate::model::catalogue_entry::CatalogueEntry;
+use std::collections::BTreeMap;
+
+const INGREDIENT_CATALOGUE_FILE: &str = "data/ingredient_catalogue.toml";
+
+#[derive(Clone)]
+pub struct IngredientCatalogueRepository {
+    entries: BTreeMap<String, CatalogueEntry>,
+}
+
+impl IngredientCatalogueRepository {
+    /// Loads the catalogue file, an absent file giving an empty catalogue.
+    pub fn load() -> Result<IngredientCatalogueRepository, String> {
+        let entries = match std::fs::read_to_string(INGREDIENT_CATALOGUE_FILE) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|error| format!("Failed to load {INGREDIENT_CATALOGUE_FILE} : {error}"))?,
+            Err(_) => BTreeMap::new(),
+        };
+        Ok(IngredientCatalogueRepository::from_entries(entries))
+    }
+
+    pub fn from_entries(entries: BTreeMap<String, CatalogueEntry>) -> IngredientCatalogueRepository {
+        IngredientCatalogueRepository { entries }
+    }
+
+    pub fn get_entry(&self, key: &str) -> Option<&CatalogueEntry> {
+        self.entries.get(key)
+    }
+
+    /// Entries sorted by key, so that the catalogue reads the same from one launch to the next.
+    pub fn list_all_entries(&self) -> impl Iterator<Item = (&String, &CatalogueEntry)> {
+        self.entries.iter()
+    }
+}
@@ -16,7 +16,9 @@ impl RecipeRepository {
     }
 
     pub fn list_all_recipes_names(&self) -> Vec<String> {
-        self.recipes.keys().cloned().collect()
+        let mut recipes_names: Vec<String> = self.recipes.keys().cloned().collect();
+        recipes_names.sort();
+        recipes_names
     }
 
     pub fn get_recipe(&self, name: &str) -> Option<Recipe> {
@@ -0,0 +1,76 @@
+use crate::model::recipe_stats::RecipeStats;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+const RECIPE_STATS_FILE: &str = "data/recipes_stats.toml";
+const BACKUP_EXTENSION: &str = "bak";
+
+#[derive(Clone)]
+pub struct RecipeStatsRepository {
+    recipes_stats: HashMap<String, RecipeStats>,
+    // False when the stats file couldn't be loaded nor put aside, so that it isn't overwritten
+    is_saved: bool,
+}
+
+impl RecipeStatsRepository {
+    /// Loads the statistics saved by a previous session, none if there was no previous session.
+    pub fn load() -> Result<RecipeStatsRepository, String> {
+        let stats_file = Path::new(RECIPE_STATS_FILE);
+        if !stats_file.exists() {
+            return Ok(RecipeStatsRepository::empty(true));
+        }
+        let content = std::fs::read_to_string(stats_file)
+            .map_err(|error| format!("Failed to read {} : {}", stats_file.display(), error))?;
+        let recipes_stats = toml::from_str(&content)
+            .map_err(|error| format!("Failed to load {} : {}", stats_file.display(), error))?;
+        Ok(RecipeStatsRepository { recipes_stats, is_saved: true })
+    }
+
+    /// Starts from empty statistics when the saved ones can't be loaded. The unreadable file is
+    /// renamed `recipes_stats.toml.bak` so that the history it holds is kept ; if it can't be,
+    /// the new statistics aren't saved at all.
+    pub fn recover(loading_error: String) -> (RecipeStatsRepository, String) {
+        let stats_file = Path::new(RECIPE_STATS_FILE);
+        let backup_file = PathBuf::from(format!("{RECIPE_STATS_FILE}.{BACKUP_EXTENSION}"));
+        match std::fs::rename(stats_file, &backup_file) {
+            Ok(()) => (
+                RecipeStatsRepository::empty(true),
+                format!("{loading_error} ; it was kept as {}", backup_file.display()),
+            ),
+            Err(error) => (
+                RecipeStatsRepository::empty(false),
+                format!("{loading_error} ; failed to keep it as {} : {}, the statistics won't be saved", backup_file.display(), error),
+            ),
+        }
+    }
+
+    fn empty(is_saved: bool) -> RecipeStatsRepository {
+        RecipeStatsRepository { recipes_stats: HashMap::new(), is_saved }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let stats_file = Path::new(RECIPE_STATS_FILE);
+        if !self.is_saved {
+            return Err(format!("The statistics aren't saved, {} couldn't be loaded", stats_file.display()));
+        }
+        if let Some(stats_directory) = stats_file.parent() {
+            std::fs::create_dir_all(stats_directory)
+                .map_err(|error| format!("Failed to create directory {} : {}", stats_directory.display(), error))?;
+        }
+        let content = toml::to_string(&self.recipes_stats)
+            .map_err(|error| format!("Failed to serialize recipes statistics : {error}"))?;
+        std::fs::write(stats_file, content)
+            .map_err(|error| format!("Failed to write {} : {}", stats_file.display(), error))
+    }
+
+    pub fn get_stats(&self, recipe_name: &str) -> RecipeStats {
+        self.recipes_stats.get(recipe_name).cloned().unwrap_or_default()
+    }
+
+    /// Updates the statistics of a recipe and saves them all, the update being kept in memory
+    /// for the session even if it can't be saved.
+    pub fn update_stats(&mut self, recipe_name: &str, update: impl FnOnce(&mut RecipeStats)) -> Result<(), String> {
+        update(self.recipes_stats.entry(recipe_name.to_string()).or_default());
+        self.save()
+    }
+}
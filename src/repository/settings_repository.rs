@@ -0,0 +1,48 @@
+use crate::model::settings::Settings;
+use std::path::PathBuf;
+
+const APPLICATION_DIRECTORY: &str = "menus_manager";
+const SETTINGS_FILE: &str = "settings.toml";
+// Used when the platform has no configuration directory
+const FALLBACK_SETTINGS_DIRECTORY: &str = "data";
+
+pub struct SettingsRepository {
+    settings_file: PathBuf,
+}
+
+impl SettingsRepository {
+    /// Settings file in the configuration directory of the platform :
+    /// `~/.config/menus_manager/settings.toml` on Linux.
+    pub fn new() -> SettingsRepository {
+        let settings_directory = dirs::config_dir()
+            .map(|config_directory| config_directory.join(APPLICATION_DIRECTORY))
+            .unwrap_or_else(|| PathBuf::from(FALLBACK_SETTINGS_DIRECTORY));
+        SettingsRepository {
+            settings_file: settings_directory.join(SETTINGS_FILE),
+        }
+    }
+
+    /// Loads the saved settings, the default ones if none were saved yet.
+    pub fn load(&self) -> Result<Settings, String> {
+        if !self.settings_file.exists() {
+            return Ok(Settings::default());
+        }
+        let content = std::fs::read_to_string(&self.settings_file)
+            .map_err(|error| format!("Failed to read {} : {}", self.settings_file.display(), error))?;
+        toml::from_str(&content).map_err(|error| format!("Failed to load {} : {}", self.settings_file.display(), error))
+    }
+
+    pub fn save(&self, settings: &Settings) -> Result<(), String> {
+        if let Some(settings_directory) = self.settings_file.parent() {
+            std::fs::create_dir_all(settings_directory)
+                .map_err(|error| format!("Failed to create directory {} : {}", settings_directory.display(), error))?;
+        }
+        let content = toml::to_string(settings).map_err(|error| format!("Failed to serialize settings : {error}"))?;
+        std::fs::write(&self.settings_file, content)
+            .map_err(|error| format!("Failed to write {} : {}", self.settings_file.display(), error))
+    }
+
+    pub fn settings_file(&self) -> &PathBuf {
+        &self.settings_file
+    }
+}
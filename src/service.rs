@@ -1,2 +1,7 @@
 pub mod recipe_service;
-pub mod excel_service;
\ No newline at end of file
+pub mod excel_service;
+pub mod import_service;
+pub mod search_service;
+pub mod catalogue_service;
+pub mod household_service;
+pub mod dashboard_service;
@@ -0,0 +1,259 @@
+use crate::model::catalogue_entry::CatalogueEntry;
+use crate::model::diet::{DietaryTag, RecipeDietProfile};
+use crate::model::ingredient::{Ingredient, WHOLE_INGREDIENT};
+use crate::model::nutrition::NutritionEstimate;
+use crate::model::package::{PackagePlan, best_package_plan};
+use crate::model::price::CostEstimate;
+use crate::model::recipe::Recipe;
+use crate::repository::ingredient_catalogue_repository::IngredientCatalogueRepository;
+use crate::service::search_service::normalize_text;
+use std::collections::BTreeMap;
+
+const MAX_LEFTOVER_SUGGESTIONS: usize = 3;
+
+pub struct IngredientCatalogueService {
+    pub catalogue_repository: IngredientCatalogueRepository,
+    // Canonical words of every catalogue key, along with the key itself, the longest keys first
+    catalogue_index: Vec<(Vec<String>, String)>,
+    // Why the catalogue file couldn't be loaded, an empty catalogue being used instead
+    pub catalogue_error: Option<String>,
+}
+
+/// Words of an ingredient name once lowercased, without accents, plural nor the
+/// precisions given between parentheses : "Pommes de terre (grenaille)" gives ["pomme", "de", "terre"].
+pub fn canonical_ingredient_words(ingredient_name: &str) -> Vec<String> {
+    let mut name_without_precisions = String::new();
+    let mut parentheses_depth: u32 = 0;
+    for character in ingredient_name.chars() {
+        match character {
+            '(' => parentheses_depth += 1,
+            ')' => parentheses_depth = parentheses_depth.saturating_sub(1),
+            _ if parentheses_depth == 0 => name_without_precisions.push(character),
+            _ => {}
+        }
+    }
+
+    normalize_text(&name_without_precisions)
+        .split(|character: char| !character.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| match word.len() > 3 {
+            true => word.strip_suffix('s').unwrap_or(word).to_string(),
+            false => word.to_string(),
+        })
+        .collect()
+}
+
+impl IngredientCatalogueService {
+    pub fn new() -> IngredientCatalogueService {
+        match IngredientCatalogueRepository::load() {
+            Ok(catalogue_repository) => IngredientCatalogueService::with_repository(catalogue_repository),
+            Err(error) => IngredientCatalogueService {
+                catalogue_error: Some(error),
+                ..IngredientCatalogueService::with_repository(IngredientCatalogueRepository::from_entries(BTreeMap::new()))
+            },
+        }
+    }
+
+    pub fn with_repository(catalogue_repository: IngredientCatalogueRepository) -> IngredientCatalogueService {
+        let mut catalogue_index: Vec<(Vec<String>, String)> = catalogue_repository
+            .list_all_entries()
+            .map(|(key, _)| (canonical_ingredient_words(key), key.clone()))
+            .filter(|(key_words, _)| !key_words.is_empty())
+            .collect();
+        catalogue_index.sort_by(|(words, key), (other_words, other_key)| {
+            other_words.len().cmp(&words.len()).then_with(|| key.cmp(other_key))
+        });
+        IngredientCatalogueService {
+            catalogue_repository,
+            catalogue_index,
+            catalogue_error: None,
+        }
+    }
+
+    /// Catalogue entries applying to an ingredient : an entry applies when its key is part of
+    /// the ingredient name ("poulet" applies to "blanc de poulet"), unless a longer key covers
+    /// it ("lait de coco" wins over "lait"). The entries come longest key first, then in the
+    /// alphabetical order of their keys : "sel et poivre" gives "poivre" before "sel", whose
+    /// nutrients, prices and packages are only used when "poivre" has none.
+    pub fn find_entries(&self, ingredient_name: &str) -> Vec<(&str, &CatalogueEntry)> {
+        let ingredient_words = canonical_ingredient_words(ingredient_name);
+        let mut matches: Vec<(usize, usize, &String)> = Vec::new();
+        for (key_words, key) in &self.catalogue_index {
+            if key_words.len() > ingredient_words.len() {
+                continue;
+            }
+            for start in 0..=(ingredient_words.len() - key_words.len()) {
+                if ingredient_words[start..start + key_words.len()] == key_words[..] {
+                    matches.push((start, start + key_words.len(), key));
+                }
+            }
+        }
+
+        let mut entries: Vec<(&str, &CatalogueEntry)> = Vec::new();
+        for (start, end, key) in &matches {
+            let is_covered_by_longer_match = matches.iter().any(|(other_start, other_end, _)| {
+                other_start <= start && end <= other_end && (other_end - other_start) > (end - start)
+            });
+            if let Some(entry) = self.catalogue_repository.get_entry(key)
+                && !is_covered_by_longer_match
+                && !entries.iter().any(|(entry_key, _)| entry_key == key)
+            {
+                entries.push((key.as_str(), entry));
+            }
+        }
+        entries
+    }
+
+    pub fn diet_profile(&self, recipe: &Recipe) -> RecipeDietProfile {
+        let mut diet_profile = RecipeDietProfile::default();
+        for ingredient in &recipe.ingredients {
+            let entries = self.find_entries(&ingredient.name);
+            if entries.is_empty() {
+                diet_profile.unknown_ingredients.push(ingredient.name.clone());
+                continue;
+            }
+            let mut tags: Vec<DietaryTag> = entries.iter().flat_map(|(_, entry)| entry.tags.iter().copied()).collect();
+            tags.sort();
+            tags.dedup();
+            if !tags.is_empty() {
+                diet_profile.tagged_ingredients.push((ingredient.name.clone(), tags));
+            }
+        }
+        diet_profile
+    }
+
+    /// Nutrients of the recipe cooked for its configured number of persons.
+    pub fn nutrition_estimate(&self, recipe: &Recipe) -> NutritionEstimate {
+        let mut recipe = recipe.clone();
+        recipe.sync_with_configured_nbr_persons();
+        let mut nutrition_estimate = NutritionEstimate {
+            nbr_persons: recipe.configured_nbr_persons,
+            ..NutritionEstimate::default()
+        };
+
+        for ingredient in &recipe.ingredients {
+            let entry = self
+                .find_entries(&ingredient.name)
+                .into_iter()
+                .map(|(_, entry)| entry)
+                .find(|entry| entry.nutrition.is_some());
+            let Some(entry) = entry else {
+                nutrition_estimate.ingredients_without_data.push(ingredient.name.clone());
+                continue;
+            };
+            match entry.nutrition.unwrap().facts_of(ingredient, entry.unit_weight) {
+                Some(facts) => nutrition_estimate.total += facts,
+                None => {
+                    let unit = if ingredient.unit == WHOLE_INGREDIENT { "" } else { ingredient.unit.as_str() };
+                    nutrition_estimate
+                        .ingredients_with_unknown_unit
+                        .push(format!("{} {unit} {}", ingredient.quantity, ingredient.name).replace("  ", " "));
+                }
+            }
+        }
+        nutrition_estimate
+    }
+
+    /// Cost of each ingredient, using the first price of its catalogue entries applying to its unit.
+    pub fn cost_estimate(&self, ingredients: &[Ingredient]) -> CostEstimate {
+        let mut cost_estimate = CostEstimate::default();
+        for ingredient in ingredients {
+            let line_cost = self.find_entries(&ingredient.name).into_iter().find_map(|(_, entry)| {
+                entry.prices.iter().find_map(|price| price.cost_of(ingredient, entry.unit_weight))
+            });
+            if line_cost.is_none() {
+                cost_estimate.ingredients_without_price.push(ingredient.name.clone());
+            }
+            cost_estimate.line_costs.push(line_cost);
+        }
+        cost_estimate
+    }
+
+    /// Cost of the recipe cooked for its configured number of persons.
+    pub fn recipe_cost_estimate(&self, recipe: &Recipe) -> CostEstimate {
+        let mut recipe = recipe.clone();
+        recipe.sync_with_configured_nbr_persons();
+        self.cost_estimate(&recipe.ingredients)
+    }
+
+    /// Packages to buy for the ingredient, choosing the size leaving the least leftover.
+    pub fn package_plan(&self, ingredient: &Ingredient) -> Option<PackagePlan> {
+        let (_, entry) = self
+            .find_entries(&ingredient.name)
+            .into_iter()
+            .find(|(_, entry)| !entry.packages.is_empty())?;
+        best_package_plan(&entry.packages, ingredient, entry.unit_weight)
+    }
+
+    /// Names of a few recipes using the same ingredient, to finish a leftover.
+    pub fn recipes_using<'a>(&self, ingredient: &Ingredient, candidate_recipes: impl Iterator<Item = &'a Recipe>) -> Vec<String> {
+        let ingredient_keys: Vec<&str> = self.find_entries(&ingredient.name).into_iter().map(|(key, _)| key).collect();
+        if ingredient_keys.is_empty() {
+            return Vec::new();
+        }
+        candidate_recipes
+            .filter(|recipe| {
+                recipe.ingredients.iter().any(|recipe_ingredient| {
+                    self.find_entries(&recipe_ingredient.name)
+                        .iter()
+                        .any(|(key, _)| ingredient_keys.contains(key))
+                })
+            })
+            .map(|recipe| recipe.name.clone())
+            .take(MAX_LEFTOVER_SUGGESTIONS)
+            .collect()
+    }
+
+    /// Fruits and vegetables of the recipe that are not in season during the month, from 1 to 12.
+    pub fn out_of_season_ingredients(&self, recipe: &Recipe, month: u8) -> Vec<String> {
+        recipe
+            .ingredients
+            .iter()
+            .filter(|ingredient| {
+                self.find_entries(&ingredient.name)
+                    .into_iter()
+                    .find(|(_, entry)| !entry.months.is_empty())
+                    .is_some_and(|(_, entry)| !entry.months.contains(&month))
+            })
+            .map(|ingredient| ingredient.name.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::price::Price;
+
+    fn priced_entry(price: f32) -> CatalogueEntry {
+        CatalogueEntry { prices: vec![Price { price, per: "unite".to_string() }], ..CatalogueEntry::default() }
+    }
+
+    fn catalogue_service(keys_and_prices: &[(&str, f32)]) -> IngredientCatalogueService {
+        let entries: BTreeMap<String, CatalogueEntry> =
+            keys_and_prices.iter().map(|(key, price)| (key.to_string(), priced_entry(*price))).collect();
+        IngredientCatalogueService::with_repository(IngredientCatalogueRepository::from_entries(entries))
+    }
+
+    fn ingredient(name: &str) -> Ingredient {
+        Ingredient { name: name.to_string(), unit: WHOLE_INGREDIENT.to_string(), quantity: 1.0 }
+    }
+
+    #[test]
+    fn a_longer_key_covers_a_shorter_one() {
+        let catalogue_service = catalogue_service(&[("lait", 1.0), ("lait de coco", 2.0)]);
+        let keys: Vec<&str> = catalogue_service.find_entries("Lait de coco").into_iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, vec!["lait de coco"]);
+    }
+
+    #[test]
+    fn keys_not_covering_each_other_come_in_alphabetical_order() {
+        let catalogue_service = catalogue_service(&[("sel", 1.0), ("poivre", 3.0), ("huile d'olive", 5.0), ("tomate", 4.0)]);
+        let keys: Vec<&str> = catalogue_service.find_entries("Sel et poivre").into_iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, vec!["poivre", "sel"]);
+        let keys: Vec<&str> =
+            catalogue_service.find_entries("Tomates séchées à l'huile d'olive").into_iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, vec!["huile d'olive", "tomate"]);
+        assert_eq!(catalogue_service.cost_estimate(&[ingredient("Sel et poivre")]).line_costs, vec![Some(3.0)]);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::model::dashboard::{DashboardWarning, WeekDashboard};
+use crate::model::ingredient::Ingredient;
+use crate::model::person_count::PersonCount;
+use crate::model::recipe::Recipe;
+use crate::model::recipe_slot::{Meal, RecipeSlot};
+use std::collections::{BTreeMap, HashMap};
+
+/// Sums up the recipes planned for the week, `is_loaded_recipe` telling whether a recipe
+/// is still among the loaded ones. `shopping_list` is what is left to buy, the ingredients
+/// being merged as in the shopping list view.
+pub fn week_dashboard(
+    selected_recipes: &HashMap<RecipeSlot, Recipe>,
+    shopping_list: &[Ingredient],
+    is_loaded_recipe: impl Fn(&str) -> bool,
+) -> WeekDashboard {
+    let planned_recipes: Vec<&Recipe> = selected_recipes.values().collect();
+    let kitchen_times: Vec<Option<u16>> = planned_recipes
+        .iter()
+        .map(|recipe| match (recipe.metadata.preparation_time, recipe.metadata.cooking_time) {
+            (None, None) => None,
+            (preparation_time, cooking_time) => Some(preparation_time.unwrap_or(0).saturating_add(cooking_time.unwrap_or(0))),
+        })
+        .collect();
+
+    let mut warnings = Vec::new();
+    let mut empty_slots: Vec<RecipeSlot> = Meal::ALL
+        .into_iter()
+        .flat_map(Meal::slots)
+        .filter(|recipe_slot| !selected_recipes.contains_key(recipe_slot))
+        .collect();
+    empty_slots.sort_by_key(|recipe_slot| (recipe_slot.day.offset(), recipe_slot.meal.position()));
+    if !empty_slots.is_empty() {
+        warnings.push(DashboardWarning::EmptySlots(empty_slots));
+    }
+    let mut nbr_times_by_recipe: BTreeMap<&str, usize> = BTreeMap::new();
+    for recipe in planned_recipes.iter() {
+        *nbr_times_by_recipe.entry(recipe.name.as_str()).or_default() += 1;
+    }
+    for (recipe_name, nbr_times) in nbr_times_by_recipe {
+        if nbr_times > 1 {
+            warnings.push(DashboardWarning::RepeatedRecipe { recipe_name: recipe_name.to_string(), nbr_times });
+        }
+    }
+    let mut missing_recipes: Vec<(&RecipeSlot, &Recipe)> =
+        selected_recipes.iter().filter(|(_, recipe)| !is_loaded_recipe(&recipe.name)).collect();
+    missing_recipes.sort_by_key(|(recipe_slot, _)| (recipe_slot.day.offset(), recipe_slot.meal.position()));
+    for (recipe_slot, recipe) in missing_recipes {
+        warnings.push(DashboardWarning::MissingRecipe { recipe_name: recipe.name.clone(), recipe_slot: *recipe_slot });
+    }
+
+    WeekDashboard {
+        nbr_planned_meals: planned_recipes.len(),
+        nbr_veggie_meals: planned_recipes.iter().filter(|recipe| recipe.is_veggie).count(),
+        total_portions: planned_recipes
+            .iter()
+            .fold(PersonCount::ZERO, |total, recipe| total.saturating_add(recipe.configured_nbr_persons)),
+        nbr_shopping_items: shopping_list.len(),
+        kitchen_time: kitchen_times.iter().flatten().fold(0, |total: u16, time| total.saturating_add(*time)),
+        nbr_meals_without_time: kitchen_times.iter().filter(|time| time.is_none()).count(),
+        warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::recipe_slot::Day;
+
+    fn recipe(name: &str, is_veggie: bool, ingredient_names: &[&str]) -> Recipe {
+        let mut recipe = Recipe::new();
+        recipe.set_name(name.to_string());
+        recipe.is_veggie = is_veggie;
+        recipe.configured_nbr_persons = PersonCount::from_whole(2);
+        for ingredient_name in ingredient_names {
+            recipe.add_ingredient(Ingredient {
+                name: ingredient_name.to_string(),
+                unit: "g".to_string(),
+                quantity: 100.0,
+            });
+        }
+        recipe
+    }
+
+    #[test]
+    fn an_empty_week_only_warns_about_its_fourteen_empty_slots() {
+        let dashboard = week_dashboard(&HashMap::new(), &[], |_| true);
+        assert_eq!(dashboard.nbr_planned_meals, 0);
+        assert_eq!(dashboard.total_portions, PersonCount::ZERO);
+        assert_eq!(dashboard.nbr_shopping_items, 0);
+        match dashboard.warnings.as_slice() {
+            [DashboardWarning::EmptySlots(empty_slots)] => {
+                assert_eq!(empty_slots.len(), 14);
+                assert_eq!(empty_slots[0], Day::Monday.slot(Meal::Noon));
+                assert_eq!(empty_slots[1], Day::Monday.slot(Meal::Evening));
+            }
+            warnings => panic!("{warnings:?}"),
+        }
+    }
+
+    #[test]
+    fn counts_meals_portions_and_shopping_items() {
+        let mut selected_recipes = HashMap::new();
+        selected_recipes.insert(Day::Monday.slot(Meal::Noon), recipe("Dahl", true, &["lentilles", "riz"]));
+        selected_recipes.insert(Day::Monday.slot(Meal::Evening), recipe("Risotto", true, &["riz", "parmesan"]));
+        selected_recipes.insert(Day::Tuesday.slot(Meal::Noon), recipe("Poulet rôti", false, &["poulet"]));
+
+        let shopping_list = recipe("Courses", false, &["lentilles", "riz", "parmesan", "poulet"]).ingredients;
+        let dashboard = week_dashboard(&selected_recipes, &shopping_list, |_| true);
+        assert_eq!(dashboard.nbr_planned_meals, 3);
+        assert_eq!(dashboard.nbr_veggie_meals, 2);
+        assert_eq!(dashboard.nbr_other_meals(), 1);
+        assert_eq!(dashboard.total_portions, PersonCount::from_whole(6));
+        assert_eq!(dashboard.nbr_shopping_items, 4);
+    }
+
+    #[test]
+    fn adds_up_the_kitchen_time_of_the_timed_recipes() {
+        let mut timed_recipe = recipe("Lasagnes", false, &[]);
+        timed_recipe.metadata.preparation_time = Some(30);
+        timed_recipe.metadata.cooking_time = Some(45);
+        let mut quick_recipe = recipe("Salade", true, &[]);
+        quick_recipe.metadata.preparation_time = Some(10);
+        let mut selected_recipes = HashMap::new();
+        selected_recipes.insert(Day::Monday.slot(Meal::Noon), timed_recipe);
+        selected_recipes.insert(Day::Monday.slot(Meal::Evening), quick_recipe);
+        selected_recipes.insert(Day::Friday.slot(Meal::Noon), recipe("Restes", false, &[]));
+
+        let dashboard = week_dashboard(&selected_recipes, &[], |_| true);
+        assert_eq!(dashboard.kitchen_time, 85);
+        assert_eq!(dashboard.nbr_meals_without_time, 1);
+    }
+
+    #[test]
+    fn warns_about_repeated_and_missing_recipes() {
+        let mut selected_recipes = HashMap::new();
+        for day in Day::ALL {
+            for meal in Meal::ALL {
+                selected_recipes.insert(day.slot(meal), recipe(&format!("{day:?} {meal:?}"), false, &[]));
+            }
+        }
+        selected_recipes.insert(Day::Sunday.slot(Meal::Evening), recipe("Monday Noon", false, &[]));
+        selected_recipes.insert(Day::Wednesday.slot(Meal::Noon), recipe("Supprimée", false, &[]));
+
+        let dashboard = week_dashboard(&selected_recipes, &[], |recipe_name| recipe_name != "Supprimée");
+        assert_eq!(
+            dashboard.warnings,
+            vec![
+                DashboardWarning::RepeatedRecipe { recipe_name: "Monday Noon".to_string(), nbr_times: 2 },
+                DashboardWarning::MissingRecipe {
+                    recipe_name: "Supprimée".to_string(),
+                    recipe_slot: Day::Wednesday.slot(Meal::Noon),
+                },
+            ]
+        );
+    }
+}
@@ -1,12 +1,19 @@
-use crate::controller::main_controller::RecipeSlot;
 use crate::model::ingredient::{Ingredient, WHOLE_INGREDIENT};
 use crate::model::menu::Menu;
+use crate::model::nutrition::{NutritionEstimate, NutritionFacts, per_person_total};
+use crate::model::person_count::PersonCount;
+use crate::model::price::{CostEstimate, format_price};
 use crate::model::recipe::Recipe;
-use crate::model::recipe::{NOON, EVENING};
-use crate::model::weekday::{
-    FRIDAY, MONDAY, SATURDAY, SUNDAY, THURSDAY, TUESDAY, WEDNESDAY, WeekDay,
+use crate::model::language::{Language, Texts};
+use crate::model::settings::Settings;
+use crate::model::shopping_list::ShoppingItemSource;
+use crate::model::recipe_slot::{Day, Meal};
+use crate::model::recipe_metadata::format_duration;
+use crate::model::weekday::WeekDay;
+use rust_xlsxwriter::{
+    Color, ConditionalFormatFormula, DataValidation, Format, FormatAlign, Note, Workbook, Worksheet,
 };
-use rust_xlsxwriter::{Color, Format, Workbook, Worksheet};
+use rust_xlsxwriter::utility::column_number_to_name;
 use native_dialog::DialogBuilder;
 use calamine::{open_workbook, Data, DataType, Reader, Sheet, ToCellDeserializer, Xlsx};
 use std::collections::HashMap;
@@ -16,56 +23,90 @@ use std::io::BufReader;
 use std::path::Path;
 use crate::service::recipe_service::RecipeService;
 
-#[derive(Debug)]
-enum DailyRecipeSlot {
-    NOON,
-    EVENING,
-}
 
+// Widths of the columns of the shopping list sheet, in characters
+const BOUGHT_COLUMN_WIDTH: f64 = 14.0;
+const INGREDIENT_NAME_COLUMN_WIDTH: f64 = 30.0;
+const QUANTITY_COLUMN_WIDTH: f64 = 16.0;
+const UNIT_COLUMN_WIDTH: f64 = 10.0;
+const PRICE_COLUMN_WIDTH: f64 = 22.0;
+const PACKAGES_COLUMN_WIDTH: f64 = 30.0;
+const SOURCES_COLUMN_WIDTH: f64 = 32.0;
+const WEEK_RESUME_COLUMNS_WIDTHS: [f64; 4] = [14.0, 45.0, 12.0, 16.0];
 
-pub fn column_header_format() -> Format {
-    let background_color = Color::RGB(0x32c1eb);
+pub fn column_header_format(settings: &Settings) -> Format {
+    let background_color = Color::RGB(settings.header_colour_rgb());
     Format::new()
         .set_background_color(background_color)
         .set_bold()
         .set_font_size(20)
 }
 
-fn write_shopping_list(workbook: &mut Workbook, menu: &Menu) {
+fn write_shopping_list(workbook: &mut Workbook, menu: &Menu, settings: &Settings) {
+    let texts = settings.language.texts();
     let mut worksheet = workbook.add_worksheet();
     let all_ingredients = menu.all_ingredients.clone();
-    worksheet.set_name("Liste de courses").unwrap();
+    worksheet.set_name(texts.shopping_list_sheet).unwrap();
 
     let starting_row = 1;
     let starting_column = 1;
 
+    let bought_column = starting_column - 1;
     let ingredient_name_column = starting_column + 0;
     let ingredient_quantity_column = starting_column + 1;
     let ingredient_unit_column = starting_column + 2;
-    let week_resume_column = starting_column + 4;
+    let ingredient_price_column = starting_column + 3;
+    let ingredient_packages_column = starting_column + 4;
+    let ingredient_sources_column = starting_column + 5;
+    let price_format = Format::new().set_num_format("0.00 €");
+    let missing_format = Format::new().set_italic().set_font_color(Color::Gray);
 
+    worksheet
+        .write_with_format(
+            starting_row,
+            bought_column,
+            texts.bought,
+            &column_header_format(settings),
+        )
+        .unwrap();
     worksheet
         .write_with_format(
             starting_row,
             ingredient_name_column,
-            "Ingrédient",
-            &column_header_format(),
+            texts.ingredient,
+            &column_header_format(settings),
         )
         .unwrap();
     worksheet
         .write_with_format(
             starting_row,
             ingredient_quantity_column,
-            "Quantité",
-            &column_header_format(),
+            texts.quantity,
+            &column_header_format(settings),
         )
         .unwrap();
     worksheet
         .write_with_format(
             starting_row,
-            week_resume_column,
-            "Résumé de la semaine",
-            &column_header_format(),
+            ingredient_price_column,
+            texts.estimated_price,
+            &column_header_format(settings),
+        )
+        .unwrap();
+    worksheet
+        .write_with_format(
+            starting_row,
+            ingredient_packages_column,
+            texts.to_buy,
+            &column_header_format(settings),
+        )
+        .unwrap();
+    worksheet
+        .write_with_format(
+            starting_row,
+            ingredient_sources_column,
+            texts.for_meals,
+            &column_header_format(settings),
         )
         .unwrap();
 
@@ -83,59 +124,274 @@ fn write_shopping_list(workbook: &mut Workbook, menu: &Menu) {
                 .write(writing_row, ingredient_unit_column, ingredient.unit)
                 .unwrap();
         }
+        match menu.shopping_list_cost.line_costs.get(i).copied().flatten() {
+            Some(line_cost) => worksheet
+                .write_with_format(writing_row, ingredient_price_column, line_cost, &price_format)
+                .unwrap(),
+            None => worksheet
+                .write_with_format(writing_row, ingredient_price_column, texts.unknown_price, &missing_format)
+                .unwrap(),
+        };
+        if let Some(package_plan) = menu.package_plans.get(i).cloned().flatten() {
+            let packages = match package_plan.has_leftover() {
+                true => format!(
+                    "{} ({})",
+                    package_plan.summary(settings.language),
+                    package_plan.leftover_summary(settings.language)
+                ),
+                false => package_plan.summary(settings.language),
+            };
+            worksheet
+                .write(writing_row, ingredient_packages_column, packages)
+                .unwrap();
+        }
+        write_ingredient_sources(
+            worksheet,
+            writing_row,
+            ingredient_sources_column,
+            &all_ingredients[i],
+            menu.shopping_list_sources.get(i).map_or(&[], Vec::as_slice),
+            settings,
+        );
+    }
+
+    let last_ingredient_row = starting_row + all_ingredients.len() as u32;
+    if !all_ingredients.is_empty() {
+        write_shopping_checklist(
+            worksheet,
+            starting_row + 1,
+            last_ingredient_row,
+            bought_column,
+            ingredient_sources_column,
+            texts,
+        );
     }
+    worksheet
+        .autofilter(starting_row, bought_column, last_ingredient_row, ingredient_sources_column)
+        .unwrap();
+    // The headers stay visible while scrolling and are printed on every page
+    worksheet.set_freeze_panes(starting_row + 1, 0).unwrap();
+    worksheet.set_repeat_rows(0, starting_row).unwrap();
+    let column_widths = [
+        (bought_column, BOUGHT_COLUMN_WIDTH),
+        (ingredient_name_column, INGREDIENT_NAME_COLUMN_WIDTH),
+        (ingredient_quantity_column, QUANTITY_COLUMN_WIDTH),
+        (ingredient_unit_column, UNIT_COLUMN_WIDTH),
+        (ingredient_price_column, PRICE_COLUMN_WIDTH),
+        (ingredient_packages_column, PACKAGES_COLUMN_WIDTH),
+        (ingredient_sources_column, SOURCES_COLUMN_WIDTH),
+    ];
+    for (column, width) in column_widths {
+        worksheet.set_column_width(column, width).unwrap();
+    }
+    worksheet.set_landscape();
+    fit_to_page_width(worksheet);
+
+    let total_row = starting_row + 2 + all_ingredients.len() as u32;
+    worksheet
+        .write_with_format(total_row, ingredient_name_column, texts.estimated_total, &Format::new().set_bold())
+        .unwrap();
+    worksheet
+        .write_with_format(
+            total_row,
+            ingredient_price_column,
+            menu.shopping_list_cost.total(),
+            &price_format.clone().set_bold(),
+        )
+        .unwrap();
+    if !menu.shopping_list_cost.is_complete() {
+        worksheet
+            .write_with_format(
+                total_row + 1,
+                ingredient_name_column,
+                format!("{} {}", menu.shopping_list_cost.ingredients_without_price.len(), texts.ingredients_without_price),
+                &missing_format,
+            )
+            .unwrap();
+    }
+
+    let mut leftovers_row = total_row + 3;
+    if !menu.leftover_suggestions.is_empty() {
+        worksheet
+            .write_with_format(leftovers_row, ingredient_name_column, texts.leftovers, &Format::new().set_bold())
+            .unwrap();
+        leftovers_row += 1;
+    }
+    for (leftover, recipe_names) in &menu.leftover_suggestions {
+        worksheet
+            .write(leftovers_row, ingredient_name_column, leftover.as_str())
+            .unwrap();
+        if !recipe_names.is_empty() {
+            worksheet
+                .write_with_format(
+                    leftovers_row,
+                    ingredient_packages_column,
+                    format!("{}{}", texts.to_finish_with, recipe_names.join(", ")),
+                    &Format::new().set_italic(),
+                )
+                .unwrap();
+        }
+        leftovers_row += 1;
+    }
+}
+
+/// Meals of the week with their cost, on a sheet of their own so that filtering the shopping
+/// list never hides them.
+fn write_week_summary(workbook: &mut Workbook, menu: &Menu, settings: &Settings) {
+    let texts = settings.language.texts();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_name(texts.week_summary).unwrap();
+
+    let starting_row = 1;
+    let week_resume_column = 1;
+    let price_format = Format::new().set_num_format("0.00 €");
+
+    worksheet
+        .write_with_format(
+            starting_row,
+            week_resume_column,
+            texts.week_summary,
+            &column_header_format(settings),
+        )
+        .unwrap();
+    for (i, width) in WEEK_RESUME_COLUMNS_WIDTHS.into_iter().enumerate() {
+        worksheet.set_column_width(week_resume_column + i as u16, width).unwrap();
+    }
+    fit_to_page_width(worksheet);
 
     let mut writing_row = starting_row + 1;
     for i in 0..menu.week_days.len() {
         let week_day = &menu.week_days[i];
         worksheet
-            .write(writing_row, week_resume_column, week_day.name.clone())
+            .write(writing_row, week_resume_column, week_day.day.name(settings.language))
             .unwrap();
 
         writing_row = writing_row + 1;
         if let Some(recipe) = week_day.noon_recipe.clone() {
             worksheet
-                .write(writing_row, week_resume_column, format!("{NOON} :"))
+                .write(writing_row, week_resume_column, Meal::Noon.name(settings.language))
                 .unwrap();
             worksheet
-                .write(writing_row, week_resume_column + 1, format!("{} ({} personnes)", recipe.name, recipe.configured_nbr_persons))
+                .write(writing_row, week_resume_column + 1, format!("{} ({} {})", recipe.name, recipe.configured_nbr_persons.label(settings.language), texts.persons.to_lowercase()))
                 .unwrap();
+            write_recipe_cost(worksheet, writing_row, week_resume_column + 2, menu.recipe_costs.get(&week_day.noon_recipe_slot), &recipe, &price_format, settings.language);
             writing_row = writing_row + 1;
         }
 
         if let Some(recipe) = week_day.evening_recipe.clone() {
             worksheet
-                .write(writing_row, week_resume_column, format!("{EVENING} :"))
+                .write(writing_row, week_resume_column, Meal::Evening.name(settings.language))
                 .unwrap();
             worksheet
-                .write(writing_row, week_resume_column + 1, format!("{} ({} personnes)", recipe.name, recipe.configured_nbr_persons))
+                .write(writing_row, week_resume_column + 1, format!("{} ({} {})", recipe.name, recipe.configured_nbr_persons.label(settings.language), texts.persons.to_lowercase()))
                 .unwrap();
+            write_recipe_cost(worksheet, writing_row, week_resume_column + 2, menu.recipe_costs.get(&week_day.evening_recipe_slot), &recipe, &price_format, settings.language);
             writing_row = writing_row + 1;
         }
         writing_row = writing_row + 1;
     }
 }
 
+/// Column ticked with a drop-down list while shopping, the bought lines being struck through.
+fn write_shopping_checklist(
+    worksheet: &mut Worksheet,
+    first_row: u32,
+    last_row: u32,
+    bought_column: u16,
+    last_column: u16,
+    texts: &Texts,
+) {
+    let data_validation = DataValidation::new()
+        .allow_list_strings(&[texts.bought_mark])
+        .unwrap()
+        .set_input_message(texts.bought_help)
+        .unwrap();
+    worksheet
+        .add_data_validation(first_row, bought_column, last_row, bought_column, &data_validation)
+        .unwrap();
+    worksheet
+        .set_column_format(bought_column, &Format::new().set_align(FormatAlign::Center))
+        .unwrap();
+
+    // The rule is written for the first row, Excel shifting it for the next ones
+    let bought_rule = format!("=${}{}=\"{}\"", column_number_to_name(bought_column), first_row + 1, texts.bought_mark);
+    let bought_format = ConditionalFormatFormula::new()
+        .set_rule(bought_rule.as_str())
+        .set_format(Format::new().set_font_strikethrough().set_font_color(Color::Gray));
+    worksheet
+        .add_conditional_format(first_row, bought_column, last_row, last_column, &bought_format)
+        .unwrap();
+}
+
+/// Prints the sheet on as many pages high as needed, but a single one wide.
+fn fit_to_page_width(worksheet: &mut Worksheet) {
+    worksheet.set_print_fit_to_pages(1, 0);
+}
+
+/// Meals needing the ingredient, the quantity each one needs being detailed in a note.
+fn write_ingredient_sources(
+    worksheet: &mut Worksheet,
+    writing_row: u32,
+    sources_column: u16,
+    ingredient: &Ingredient,
+    sources: &[ShoppingItemSource],
+    settings: &Settings,
+) {
+    if sources.is_empty() {
+        return;
+    }
+    let meals: Vec<String> = sources.iter().map(|source| source.meal(settings.language)).collect();
+    worksheet
+        .write(writing_row, sources_column, meals.join(", "))
+        .unwrap();
+    let breakdown: Vec<String> = sources
+        .iter()
+        .map(|source| source.breakdown(&ingredient.unit, settings.language))
+        .collect();
+    let note = Note::new(breakdown.join("\n")).add_author_prefix(false);
+    worksheet
+        .insert_note(writing_row, sources_column, &note)
+        .unwrap();
+}
+
+fn write_recipe_cost(
+    worksheet: &mut Worksheet,
+    writing_row: u32,
+    column: u16,
+    cost_estimate: Option<&CostEstimate>,
+    recipe: &Recipe,
+    price_format: &Format,
+    language: Language,
+) {
+    if let Some(cost_estimate) = cost_estimate {
+        worksheet
+            .write_with_format(writing_row, column, cost_estimate.total(), price_format)
+            .unwrap();
+        worksheet
+            .write(
+                writing_row,
+                column + 1,
+                format!(
+                    "{} {}",
+                    format_price(cost_estimate.per_portion(recipe.configured_nbr_persons), language),
+                    language.texts().per_portion
+                ),
+            )
+            .unwrap();
+    }
+}
+
 fn write_recipe(
     worksheet: &mut Worksheet,
     week_day: WeekDay,
-    daily_recipe_slot: DailyRecipeSlot,
+    meal: Meal,
     starting_row: u32,
     starting_column: u16,
+    settings: &Settings,
 ) -> u32 {
-    let mut daily_recipe_slot_name = "";
-    let mut recipe_to_write: Option<Recipe>;
-
-    match daily_recipe_slot {
-        DailyRecipeSlot::NOON => {
-            daily_recipe_slot_name = NOON;
-            recipe_to_write = week_day.noon_recipe;
-        }
-        DailyRecipeSlot::EVENING => {
-            daily_recipe_slot_name = EVENING;
-            recipe_to_write = week_day.evening_recipe;
-        }
-    }
+    let texts = settings.language.texts();
+    let daily_recipe_slot_name = meal.name(settings.language);
+    let recipe_to_write = week_day.recipe_of(meal).cloned();
 
     if let Some(recipe) = recipe_to_write {
         worksheet
@@ -143,7 +399,15 @@ fn write_recipe(
                 starting_row,
                 starting_column,
                 daily_recipe_slot_name,
-                &column_header_format(),
+                &column_header_format(settings),
+            )
+            .unwrap();
+        worksheet
+            .write_with_format(
+                starting_row,
+                starting_column + 1,
+                settings.meal_time(meal),
+                &column_header_format(settings),
             )
             .unwrap();
         worksheet
@@ -157,12 +421,12 @@ fn write_recipe(
         worksheet.write(
             starting_row + 1,
             starting_column + 1,
-            recipe.configured_nbr_persons,
+            recipe.configured_nbr_persons.as_f32(),
         ).unwrap();
         worksheet.write(
             starting_row + 1,
             starting_column + 2,
-            "Personnes",
+            texts.persons,
         ).unwrap();
 
         let mut current_row = starting_row + 2;
@@ -194,7 +458,43 @@ fn write_recipe(
             current_row = current_row + 1;
         }
 
-        current_row += 2;
+        current_row += 1;
+
+        let metadata = &recipe.metadata;
+        let mut metadata_rows: Vec<(&str, String)> = Vec::new();
+        if let Some(preparation_time) = metadata.preparation_time {
+            metadata_rows.push((texts.preparation, format_duration(preparation_time)));
+        }
+        if let Some(cooking_time) = metadata.cooking_time {
+            metadata_rows.push((texts.cooking, format_duration(cooking_time)));
+        }
+        if let Some(difficulty) = metadata.difficulty {
+            metadata_rows.push((texts.difficulty, difficulty.label(settings.language).to_string()));
+        }
+        if !metadata.tags.is_empty() {
+            metadata_rows.push((texts.tags, metadata.tags.join(", ")));
+        }
+        if let Some(category) = &metadata.category {
+            metadata_rows.push((texts.category, category.clone()));
+        }
+        if let Some(season) = &metadata.season {
+            metadata_rows.push((texts.season, season.clone()));
+        }
+        if let Some(author) = &metadata.author {
+            metadata_rows.push((texts.author, author.clone()));
+        }
+        if let Some(source) = &metadata.source {
+            metadata_rows.push((texts.source, source.clone()));
+        }
+        for (label, value) in metadata_rows {
+            worksheet
+                .write_with_format(current_row, starting_column, label, &Format::new().set_italic())
+                .unwrap();
+            worksheet.write(current_row, starting_column + 1, value).unwrap();
+            current_row += 1;
+        }
+
+        current_row += 1;
 
         for i in 0..recipe.steps.len() {
             worksheet
@@ -208,9 +508,93 @@ fn write_recipe(
     }
 }
 
-fn write_day(workbook: &mut Workbook, week_day: WeekDay) {
+fn write_nutrition(workbook: &mut Workbook, menu: &Menu, settings: &Settings) {
+    let texts = settings.language.texts();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_name(texts.nutrition_sheet).unwrap();
+    worksheet.set_landscape();
+    fit_to_page_width(worksheet);
+
+    let starting_row = 1;
+    let starting_column = 1;
+    for (i, header) in texts.nutrition_headers.iter().enumerate() {
+        worksheet
+            .write_with_format(starting_row, starting_column + i as u16, *header, &column_header_format(settings))
+            .unwrap();
+    }
+
+    let number_format = Format::new().set_num_format("0");
+    let total_format = Format::new().set_bold().set_num_format("0");
+    let missing_format = Format::new().set_italic().set_font_color(Color::RGB(0xc00000));
+    let write_facts = |worksheet: &mut Worksheet, row: u32, facts: &NutritionFacts, format: &Format| {
+        let values = [facts.kcal, facts.protein, facts.carbs, facts.fat, facts.fibre];
+        for (i, value) in values.iter().enumerate() {
+            worksheet
+                .write_with_format(row, starting_column + 4 + i as u16, *value, format)
+                .unwrap();
+        }
+    };
+
+    let mut writing_row = starting_row + 1;
+    let mut days_totals: Vec<NutritionFacts> = Vec::new();
+    for week_day in menu.week_days.iter() {
+        let mut day_estimates: Vec<&NutritionEstimate> = Vec::new();
+        for meal in Meal::ALL {
+            let recipe_slot = week_day.day.slot(meal);
+            let (Some(recipe), Some(nutrition_estimate)) =
+                (week_day.recipe_of(meal), menu.nutrition_estimates.get(&recipe_slot))
+            else {
+                continue;
+            };
+            worksheet.write(writing_row, starting_column, week_day.day.name(settings.language)).unwrap();
+            worksheet.write(writing_row, starting_column + 1, meal.name(settings.language)).unwrap();
+            worksheet.write(writing_row, starting_column + 2, recipe.name.clone()).unwrap();
+            worksheet
+                .write(writing_row, starting_column + 3, nutrition_estimate.nbr_persons.as_f32())
+                .unwrap();
+            write_facts(worksheet, writing_row, &nutrition_estimate.per_portion(), &number_format);
+            if !nutrition_estimate.is_complete() {
+                worksheet
+                    .write_with_format(writing_row, starting_column + 9, nutrition_estimate.missing_summary(settings.language), &missing_format)
+                    .unwrap();
+            }
+            day_estimates.push(nutrition_estimate);
+            writing_row += 1;
+        }
+        if day_estimates.is_empty() {
+            continue;
+        }
+
+        let (day_total, is_complete) = per_person_total(day_estimates);
+        worksheet
+            .write_with_format(writing_row, starting_column, format!("{} {}", texts.total, week_day.day.name(settings.language)), &Format::new().set_bold())
+            .unwrap();
+        write_facts(worksheet, writing_row, &day_total, &total_format);
+        if !is_complete {
+            worksheet
+                .write_with_format(writing_row, starting_column + 9, texts.incomplete_estimate, &missing_format)
+                .unwrap();
+        }
+        days_totals.push(day_total);
+        writing_row += 2;
+    }
+
+    if !days_totals.is_empty() {
+        let mut week_total = NutritionFacts::default();
+        for day_total in days_totals.iter() {
+            week_total += *day_total;
+        }
+        worksheet
+            .write_with_format(writing_row, starting_column, texts.daily_average, &Format::new().set_bold())
+            .unwrap();
+        write_facts(worksheet, writing_row, &week_total.scaled(1.0 / days_totals.len() as f32), &total_format);
+    }
+}
+
+fn write_day(workbook: &mut Workbook, week_day: WeekDay, settings: &Settings) {
     let mut worksheet = workbook.add_worksheet();
-    worksheet.set_name(week_day.clone().name).unwrap();
+    worksheet.set_name(week_day.day.name(settings.language)).unwrap();
+    fit_to_page_width(worksheet);
 
     let mut starting_row = 1;
     let starting_column = 1;
@@ -218,57 +602,48 @@ fn write_day(workbook: &mut Workbook, week_day: WeekDay) {
     let last_written_row = write_recipe(
         &mut worksheet,
         week_day.clone(),
-        DailyRecipeSlot::NOON,
+        Meal::Noon,
         starting_row,
         starting_column,
+        settings,
     );
     starting_row = last_written_row + 3;
     let _ = write_recipe(
         &mut worksheet,
         week_day,
-        DailyRecipeSlot::EVENING,
+        Meal::Evening,
         starting_row,
         starting_column,
+        settings,
     );
 }
 
-pub fn write_excel_menu(menu: &Menu) {
+pub fn write_excel_menu(menu: &Menu, settings: &Settings) -> Result<(), String> {
     let mut workbook = Workbook::new();
 
-    let recipes_slots_associated_to_week_days = HashMap::from([
-        (RecipeSlot::MondayNoon, MONDAY),
-        (RecipeSlot::MondayEvening, MONDAY),
-        (RecipeSlot::TuesdayNoon, TUESDAY),
-        (RecipeSlot::TuesdayEvening, TUESDAY),
-        (RecipeSlot::WednesdayNoon, WEDNESDAY),
-        (RecipeSlot::WednesdayEvening, WEDNESDAY),
-        (RecipeSlot::ThursdayNoon, THURSDAY),
-        (RecipeSlot::ThursdayEvening, THURSDAY),
-        (RecipeSlot::FridayNoon, FRIDAY),
-        (RecipeSlot::FridayEvening, FRIDAY),
-        (RecipeSlot::SaturdayNoon, SATURDAY),
-        (RecipeSlot::SaturdayEvening, SATURDAY),
-        (RecipeSlot::SundayNoon, SUNDAY),
-        (RecipeSlot::SundayEvening, SUNDAY),
-    ]);
-
-    write_shopping_list(&mut workbook, &menu);
+    write_shopping_list(&mut workbook, &menu, settings);
+    write_week_summary(&mut workbook, menu, settings);
 
     for day in menu.week_days.iter() {
         if day.clone().noon_recipe.is_some() || day.clone().evening_recipe.is_some() {
-            write_day(&mut workbook, day.clone());
+            write_day(&mut workbook, day.clone(), settings);
         }
     }
 
-    workbook.save("menu.xlsx").expect("Write failed");
+    write_nutrition(&mut workbook, menu, settings);
+
+    workbook
+        .save(&settings.output_file)
+        .map_err(|error| format!("Failed to write {} : {}", settings.output_file, error))
 }
 
 
-pub fn extract_recipe(recipe_service: &RecipeService, sheet_range: &calamine::Range<Data>, slot_name: &str) -> Option<Recipe> {
+/// Reads the recipe written under the name of the meal, in whichever language the workbook was exported.
+pub fn extract_recipe(recipe_service: &RecipeService, sheet_range: &calamine::Range<Data>, meal: Meal) -> Option<Recipe> {
     let mut starting_cell: [usize; 2] = [9999, 9999];
     for (row, col, data) in sheet_range.cells() {
         let content_as_string = data.to_string();
-        if content_as_string == slot_name.to_string() {
+        if Meal::parse(&content_as_string) == Some(meal) {
             starting_cell = [row, col];
             break;
         }
@@ -280,18 +655,19 @@ pub fn extract_recipe(recipe_service: &RecipeService, sheet_range: &calamine::Ra
     let recipe_name_col = starting_cell.clone()[1];
     let recipe_name = sheet_range.get((recipe_name_row, recipe_name_col)).expect("Impossible to get recipe_name from sheet");
 
-    let recipe_configured_persons: Option<u8> = {
+    let recipe_configured_persons: Option<PersonCount> = {
         let value_at_pos = sheet_range.get((recipe_name_row, recipe_name_col + 1));
         if value_at_pos.unwrap().clone() == Data::Empty {
             None
         } else {
             let value_at_pos = value_at_pos.unwrap();
-            Some(value_at_pos.as_i64().expect(format!("Impossible to convert {value_at_pos} as i64").as_str()) as u8)
+            let nbr_persons = value_at_pos.as_f64().expect(format!("Impossible to convert {value_at_pos} as f64").as_str());
+            Some(PersonCount::rounded_up(nbr_persons as f32, true))
         }
     };
     if let Some(recipe_configured_persons) = recipe_configured_persons && let Some(recipe) = recipe_service.find_recipe_by_name(&recipe_name.to_string()) {
         let mut recipe_to_return = recipe.clone();
-        recipe_to_return.configured_nbr_persons = recipe_configured_persons.clone();
+        recipe_to_return.configured_nbr_persons = recipe_configured_persons;
         return Some(recipe_to_return);
     }
 
@@ -300,8 +676,8 @@ pub fn extract_recipe(recipe_service: &RecipeService, sheet_range: &calamine::Ra
 
 pub fn extract_data_from_sheet(recipe_service: &RecipeService, current_week_day: &WeekDay, sheet_range: &calamine::Range<Data>, workbook: &Xlsx<BufReader<File>>) -> WeekDay {
     let mut completed_week_day = current_week_day.clone();
-    completed_week_day.noon_recipe = extract_recipe(recipe_service, sheet_range, NOON);
-    completed_week_day.evening_recipe = extract_recipe(recipe_service, sheet_range, EVENING);
+    completed_week_day.noon_recipe = extract_recipe(recipe_service, sheet_range, Meal::Noon);
+    completed_week_day.evening_recipe = extract_recipe(recipe_service, sheet_range, Meal::Evening);
 
     completed_week_day
 }
@@ -313,10 +689,10 @@ pub fn read_from_excel_menu(recipe_service: &RecipeService, week_days: Vec<WeekD
         .unwrap();
     let mut path_as_str = "";
 
-    let arranged_week_days: HashMap<String, WeekDay> = {
-        let mut hashmap: HashMap<String, WeekDay> = HashMap::new();
+    let arranged_week_days: HashMap<Day, WeekDay> = {
+        let mut hashmap: HashMap<Day, WeekDay> = HashMap::new();
         for week_day in week_days.clone() {
-            hashmap.insert(week_day.clone().name, week_day.clone());
+            hashmap.insert(week_day.day, week_day.clone());
         }
         hashmap
     };
@@ -326,8 +702,8 @@ pub fn read_from_excel_menu(recipe_service: &RecipeService, week_days: Vec<WeekD
     if let Some(path) = path {
         path_as_str = path.to_str().unwrap();
         let mut workbook: Xlsx<_> = open_workbook(path.clone()).expect(format!("Failed to open workbook '{}'", path_as_str).as_str());
-        let all_week_days_names_as_vector: Vec<String> = week_days.iter().map(|day| day.name.clone()).collect();
-        let workbook_sheets: Vec<String> = workbook.sheet_names().clone().iter().map(|sheet_name| sheet_name.to_string()).filter(|sheet_name| { all_week_days_names_as_vector.contains(sheet_name) }).collect();
+        // Day sheets are recognised in any language
+        let workbook_sheets: Vec<String> = workbook.sheet_names().clone().iter().map(|sheet_name| sheet_name.to_string()).filter(|sheet_name| { Day::parse(sheet_name).is_some() }).collect();
 
         let arranged_sheets = {
             let mut hashmap: HashMap<String, calamine::Range<Data>> = HashMap::new();
@@ -338,7 +714,7 @@ pub fn read_from_excel_menu(recipe_service: &RecipeService, week_days: Vec<WeekD
         };
 
         for sheet_name in workbook_sheets {
-            let current_week_day = arranged_week_days.get(&sheet_name).expect(format!("Failed to find week_day '{}'", sheet_name).as_str());
+            let current_week_day = arranged_week_days.get(&Day::parse(&sheet_name).unwrap()).expect(format!("Failed to find week_day '{}'", sheet_name).as_str());
             let sheet_range = arranged_sheets.get(&sheet_name).expect(format!("Unable to extract sheet range from arranged_sheets {sheet_name}").as_str());
             loaded_week_days.push(extract_data_from_sheet(recipe_service, current_week_day, sheet_range, &workbook))
         }
@@ -0,0 +1,63 @@
+use crate::model::diet::RecipeDietProfile;
+use crate::model::household::HouseholdMember;
+use crate::model::language::Language;
+use crate::model::person_count::PersonCount;
+use crate::repository::household_repository::HouseholdRepository;
+
+pub struct HouseholdService {
+    pub household_repository: HouseholdRepository,
+    // Why the household file couldn't be loaded, a household without any member being used instead
+    pub household_error: Option<String>,
+}
+
+impl HouseholdService {
+    pub fn new() -> HouseholdService {
+        let (household_repository, household_error) = match HouseholdRepository::load() {
+            Ok(household_repository) => (household_repository, None),
+            Err(error) => (HouseholdRepository::empty(), Some(error)),
+        };
+        HouseholdService {
+            household_repository,
+            household_error,
+        }
+    }
+
+    pub fn has_members(&self) -> bool {
+        !self.household_repository.list_all_members().is_empty()
+    }
+
+    pub fn present_members<'a>(&'a self, absent_members: &'a [String]) -> impl Iterator<Item = &'a HouseholdMember> {
+        self.household_repository
+            .list_all_members()
+            .iter()
+            .filter(move |member| !absent_members.contains(&member.name))
+    }
+
+    /// Number of persons to cook for, the portions of the present members being
+    /// rounded up so that nobody goes hungry : two adults and a child eating a third
+    /// of a portion give 3 persons, or 2.5 persons when half portions are allowed.
+    pub fn nbr_persons(&self, absent_members: &[String], allow_half_portions: bool) -> PersonCount {
+        let portions: f32 = self.present_members(absent_members).map(|member| member.portion).sum();
+        PersonCount::rounded_up(portions, allow_half_portions)
+    }
+
+    /// Present members who can't eat the recipe, along with the restriction and the
+    /// ingredients at fault, such as "Léa (Sans gluten : farine)".
+    pub fn conflicts(&self, diet_profile: &RecipeDietProfile, absent_members: &[String], language: Language) -> Vec<String> {
+        let mut conflicts: Vec<String> = Vec::new();
+        for member in self.present_members(absent_members) {
+            for restriction in &member.restrictions {
+                let conflicting_ingredients = diet_profile.conflicting_ingredients(*restriction);
+                if !conflicting_ingredients.is_empty() {
+                    conflicts.push(format!(
+                        "{} ({} : {})",
+                        member.name,
+                        restriction.label(language),
+                        conflicting_ingredients.join(", ")
+                    ));
+                }
+            }
+        }
+        conflicts
+    }
+}
@@ -0,0 +1,579 @@
+use crate::model::ingredient::{Ingredient, WHOLE_INGREDIENT, parse_quantity, unicode_fraction_value};
+use crate::model::recipe::Recipe;
+use crate::model::recipe_metadata::parse_iso_8601_duration;
+use crate::service::recipe_service::{apply_recipe_metadata_line, parse_boolean};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+pub const IMPORTABLE_EXTENSIONS: [&str; 5] = ["html", "htm", "md", "markdown", "csv"];
+
+// Units as they may be written in an imported recipe, associated to the unit used in our recipe files.
+// Multi-words units are listed before their shorter prefixes so that the longest one wins.
+const UNIT_ALIASES: [(&str, &str); 53] = [
+    ("cuillères à soupe", "cs"),
+    ("cuillère à soupe", "cs"),
+    ("cuil. à soupe", "cs"),
+    ("c. à soupe", "cs"),
+    ("c. à s.", "cs"),
+    ("c.à.s", "cs"),
+    ("càs", "cs"),
+    ("cas", "cs"),
+    ("cs", "cs"),
+    ("tablespoons", "cs"),
+    ("tablespoon", "cs"),
+    ("tbsp", "cs"),
+    ("cuillères à café", "cc"),
+    ("cuillère à café", "cc"),
+    ("cuil. à café", "cc"),
+    ("c. à café", "cc"),
+    ("c. à c.", "cc"),
+    ("c.à.c", "cc"),
+    ("càc", "cc"),
+    ("cac", "cc"),
+    ("cc", "cc"),
+    ("teaspoons", "cc"),
+    ("teaspoon", "cc"),
+    ("tsp", "cc"),
+    ("kilogrammes", "kg"),
+    ("kilogramme", "kg"),
+    ("kg", "kg"),
+    ("grammes", "g"),
+    ("gramme", "g"),
+    ("gr", "g"),
+    ("g", "g"),
+    ("millilitres", "ml"),
+    ("ml", "ml"),
+    ("centilitres", "cl"),
+    ("cl", "cl"),
+    ("dl", "dl"),
+    ("litres", "l"),
+    ("litre", "l"),
+    ("l", "l"),
+    ("pincées", "pincée"),
+    ("pincée", "pincée"),
+    ("pots", "pot"),
+    ("pot", "pot"),
+    ("boîtes", "boite"),
+    ("boîte", "boite"),
+    ("boites", "boite"),
+    ("boite", "boite"),
+    ("sachets", "sachet"),
+    ("sachet", "sachet"),
+    ("tranches", "tranches"),
+    ("tranche", "tranches"),
+    ("mottes", "motte"),
+    ("motte", "motte"),
+];
+
+const MARKDOWN_INGREDIENTS_HEADINGS: [&str; 2] = ["ingrédient", "ingredient"];
+const MARKDOWN_NBR_PERSONS_KEYWORDS: [&str; 4] = ["personne", "portion", "part", "serving"];
+const CSV_METADATA__NAME: [&str; 2] = ["nom", "name"];
+const CSV_METADATA__NBR_PERSONS: [&str; 3] = ["personnes", "portions", "persons"];
+const CSV_METADATA__VEGGIE: [&str; 2] = ["veggie", "végétarien"];
+
+/// Reads a recipe written in a foreign format and converts it to our `Recipe` model.
+/// The format is deduced from the extension of the file.
+pub fn import_recipe_file(recipe_file: &Path) -> Result<Recipe, String> {
+    let content = fs::read_to_string(recipe_file)
+        .map_err(|error| format!("Failed to read file {} : {}", recipe_file.display(), error))?;
+    let extension = recipe_file
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    let recipe = match extension.as_str() {
+        "html" | "htm" => import_from_json_ld(&content)?,
+        "md" | "markdown" => import_from_markdown(&content)?,
+        "csv" => {
+            let default_name = recipe_file
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().replace('_', " "))
+                .unwrap_or_default();
+            import_from_csv(&content, &default_name)?
+        }
+        _ => {
+            return Err(format!(
+                "Unsupported file {} (accepted extensions : {})",
+                recipe_file.display(),
+                IMPORTABLE_EXTENSIONS.join(", ")
+            ));
+        }
+    };
+
+    if recipe.name.trim().is_empty() {
+        return Err(format!("No recipe name found in {}", recipe_file.display()));
+    }
+    if recipe.ingredients.is_empty() {
+        return Err(format!("No ingredient found in {}", recipe_file.display()));
+    }
+    Ok(recipe)
+}
+
+/// Extracts the first schema.org `Recipe` found in the JSON-LD scripts of an HTML page.
+pub fn import_from_json_ld(html: &str) -> Result<Recipe, String> {
+    for json_ld in extract_json_ld_scripts(html) {
+        let Ok(value) = serde_json::from_str::<Value>(json_ld.trim()) else {
+            continue;
+        };
+        if let Some(recipe_node) = find_recipe_node(&value) {
+            return recipe_from_json_ld_node(recipe_node);
+        }
+    }
+    Err("No schema.org Recipe found in the page".to_string())
+}
+
+fn extract_json_ld_scripts(html: &str) -> Vec<&str> {
+    let mut scripts = Vec::new();
+    let lowercase_html = html.to_ascii_lowercase();
+    let mut search_from = 0;
+
+    while let Some(tag_start) = lowercase_html[search_from..].find("<script") {
+        let tag_start = search_from + tag_start;
+        let Some(tag_end) = lowercase_html[tag_start..].find('>') else {
+            break;
+        };
+        let content_start = tag_start + tag_end + 1;
+        let Some(content_end) = lowercase_html[content_start..].find("</script") else {
+            break;
+        };
+        let content_end = content_start + content_end;
+
+        if lowercase_html[tag_start..content_start].contains("application/ld+json") {
+            scripts.push(&html[content_start..content_end]);
+        }
+        search_from = content_end;
+    }
+    scripts
+}
+
+fn is_recipe_node(value: &Value) -> bool {
+    match value.get("@type") {
+        Some(Value::String(node_type)) => node_type == "Recipe",
+        Some(Value::Array(node_types)) => node_types.iter().any(|node_type| node_type == "Recipe"),
+        _ => false,
+    }
+}
+
+fn find_recipe_node(value: &Value) -> Option<&Value> {
+    match value {
+        Value::Array(nodes) => nodes.iter().find_map(find_recipe_node),
+        Value::Object(object) => {
+            if is_recipe_node(value) {
+                return Some(value);
+            }
+            object.get("@graph").and_then(find_recipe_node)
+        }
+        _ => None,
+    }
+}
+
+fn json_value_as_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(string) => Some(decode_html_entities(string)),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Array(values) => values.iter().find_map(json_value_as_text),
+        _ => None,
+    }
+}
+
+fn recipe_from_json_ld_node(node: &Value) -> Result<Recipe, String> {
+    let mut recipe = Recipe::new();
+    recipe.set_name(node.get("name").and_then(json_value_as_text).unwrap_or_default().trim().to_string());
+
+    let recipe_yield = node
+        .get("recipeYield")
+        .and_then(json_value_as_text)
+        .ok_or("The recipe does not tell how many persons it is for (recipeYield)")?;
+    recipe.nbr_persons = first_number_in_text(&recipe_yield)
+        .ok_or(format!("Failed to read the number of persons from \"{recipe_yield}\""))?;
+    recipe.reset_configured_nbr_persons();
+
+    if let Some(diets) = node.get("suitableForDiet") {
+        let diets = diets.to_string().to_lowercase();
+        recipe.is_veggie = diets.contains("vegetarian") || diets.contains("vegan");
+    }
+
+    let ingredients = node.get("recipeIngredient").or(node.get("ingredients"));
+    if let Some(Value::Array(ingredients)) = ingredients {
+        for ingredient in ingredients.iter().filter_map(json_value_as_text) {
+            if let Some(ingredient) = parse_ingredient_text(&ingredient) {
+                recipe.add_ingredient(ingredient);
+            }
+        }
+    }
+
+    if let Some(instructions) = node.get("recipeInstructions") {
+        collect_json_ld_steps(instructions, &mut recipe.steps);
+    }
+
+    let metadata = &mut recipe.metadata;
+    metadata.preparation_time = node.get("prepTime").and_then(json_value_as_text).and_then(|time| parse_iso_8601_duration(&time));
+    metadata.cooking_time = node.get("cookTime").and_then(json_value_as_text).and_then(|time| parse_iso_8601_duration(&time));
+    metadata.source = node.get("url").and_then(json_value_as_text);
+    metadata.category = node.get("recipeCategory").and_then(|category| match category {
+        Value::Array(categories) => categories.first(),
+        _ => Some(category),
+    }).and_then(json_value_as_text);
+    metadata.author = node.get("author").and_then(|author| match author {
+        Value::Array(authors) => authors.first().and_then(|author| author.get("name").or(Some(author))),
+        Value::Object(_) => author.get("name"),
+        _ => Some(author),
+    }).and_then(json_value_as_text);
+    if let Some(keywords) = node.get("keywords").and_then(json_value_as_text) {
+        metadata.tags = keywords
+            .split(',')
+            .map(|keyword| keyword.trim().to_lowercase())
+            .filter(|keyword| !keyword.is_empty())
+            .collect();
+    }
+    Ok(recipe)
+}
+
+// Instructions may be a plain text, a list of texts, or a list of HowToStep / HowToSection.
+fn collect_json_ld_steps(instructions: &Value, steps: &mut Vec<String>) {
+    match instructions {
+        Value::String(text) => {
+            for line in decode_html_entities(text).lines() {
+                if !line.trim().is_empty() {
+                    steps.push(line.trim().to_string());
+                }
+            }
+        }
+        Value::Array(instructions) => {
+            for instruction in instructions {
+                collect_json_ld_steps(instruction, steps);
+            }
+        }
+        Value::Object(object) => {
+            if let Some(section_steps) = object.get("itemListElement") {
+                collect_json_ld_steps(section_steps, steps);
+            } else if let Some(text) = object.get("text").or(object.get("name")) {
+                collect_json_ld_steps(text, steps);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn decode_html_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&#39;", "'")
+        .replace("&#039;", "'")
+        .replace("&apos;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Reads a recipe written with the following Markdown layout :
+///
+/// ```text
+/// # Name of the recipe
+/// Pour 4 personnes
+/// Veggie: oui
+/// Cuisson: 30 min
+///
+/// ## Ingrédients
+/// - 700 g potimarron
+///
+/// ## Préparation
+/// 1. First step
+/// ```
+pub fn import_from_markdown(content: &str) -> Result<Recipe, String> {
+    enum Section {
+        Header,
+        Ingredients,
+        Steps,
+    }
+
+    let mut recipe = Recipe::new();
+    let mut nbr_persons: Option<u8> = None;
+    let mut section = Section::Header;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(heading) = line.strip_prefix("# ") {
+            recipe.set_name(heading.trim().to_string());
+            continue;
+        }
+        if line.starts_with("##") {
+            let heading = line.trim_start_matches('#').trim().to_lowercase();
+            section = if MARKDOWN_INGREDIENTS_HEADINGS.iter().any(|keyword| heading.contains(keyword)) {
+                Section::Ingredients
+            } else {
+                Section::Steps
+            };
+            continue;
+        }
+
+        match section {
+            Section::Header => {
+                let lowercase_line = line.to_lowercase();
+                if MARKDOWN_NBR_PERSONS_KEYWORDS.iter().any(|keyword| lowercase_line.contains(keyword)) {
+                    nbr_persons = nbr_persons.or(first_number_in_text(line));
+                } else if let Some((key, value)) = line.split_once(':') {
+                    apply_recipe_metadata_line(&mut recipe, key, value);
+                }
+            }
+            Section::Ingredients => {
+                if let Some(ingredient) = parse_ingredient_text(strip_list_marker(line)) {
+                    recipe.add_ingredient(ingredient);
+                }
+            }
+            Section::Steps => recipe.add_step(strip_list_marker(line).to_string()),
+        }
+    }
+
+    recipe.nbr_persons = nbr_persons.ok_or("No line telling how many persons the recipe is for (e.g. \"Pour 4 personnes\")")?;
+    recipe.reset_configured_nbr_persons();
+    Ok(recipe)
+}
+
+fn strip_list_marker(line: &str) -> &str {
+    let line = line.trim_start_matches(['-', '*', '+', '•']).trim_start();
+    let digits_end = line.find(|character: char| !character.is_ascii_digit()).unwrap_or(0);
+    if digits_end > 0 && (line[digits_end..].starts_with(". ") || line[digits_end..].starts_with(") ")) {
+        return line[digits_end + 1..].trim_start();
+    }
+    line
+}
+
+/// Reads a CSV of ingredients (`quantité;unité;ingrédient`, `,` is also accepted as separator
+/// when the first row has no `;`, the whole file then being read with it).
+/// The recipe name, number of persons and veggie flag can be given on leading `nom;...`,
+/// `personnes;...` and `veggie;...` rows, the name defaults to the name of the file.
+pub fn import_from_csv(content: &str, default_name: &str) -> Result<Recipe, String> {
+    let mut recipe = Recipe::new();
+    recipe.set_name(default_name.trim().to_string());
+    let mut nbr_persons: Option<u8> = None;
+    let separator = match content.lines().find(|line| !line.trim().is_empty()) {
+        Some(first_line) if !first_line.contains(';') => ',',
+        _ => ';',
+    };
+
+    for line in content.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields = split_csv_line(line, separator);
+        let first_field = fields[0].to_lowercase();
+
+        if CSV_METADATA__NAME.contains(&first_field.as_str()) && fields.len() > 1 {
+            recipe.set_name(fields[1].clone());
+        } else if CSV_METADATA__NBR_PERSONS.contains(&first_field.as_str()) && fields.len() > 1 {
+            nbr_persons = first_number_in_text(&fields[1]);
+        } else if CSV_METADATA__VEGGIE.contains(&first_field.as_str()) && fields.len() > 1 {
+            recipe.is_veggie = parse_boolean(&fields[1].to_lowercase()).unwrap_or(false);
+        } else if let Some(quantity) = parse_quantity(&fields[0]) {
+            let (unit, name) = match fields.len() {
+                2 => (WHOLE_INGREDIENT.to_string(), fields[1].clone()),
+                3.. if fields[1].is_empty() => (WHOLE_INGREDIENT.to_string(), fields[2].clone()),
+                3.. => (fields[1].clone(), fields[2].clone()),
+                _ => continue,
+            };
+            recipe.add_ingredient(Ingredient { name, unit, quantity });
+        }
+        // Any other row is a header, it is ignored
+    }
+
+    recipe.nbr_persons = nbr_persons.ok_or("No \"personnes;<number>\" row in the CSV file")?;
+    recipe.reset_configured_nbr_persons();
+    Ok(recipe)
+}
+
+fn split_csv_line(line: &str, separator: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current_field = String::new();
+    let mut in_quotes = false;
+
+    for character in line.chars() {
+        match character {
+            '"' => in_quotes = !in_quotes,
+            _ if character == separator && !in_quotes => {
+                fields.push(current_field.trim().to_string());
+                current_field.clear();
+            }
+            _ => current_field.push(character),
+        }
+    }
+    fields.push(current_field.trim().to_string());
+    fields
+}
+
+fn first_number_in_text(text: &str) -> Option<u8> {
+    text.split(|character: char| !character.is_ascii_digit())
+        .find(|part| !part.is_empty())
+        .and_then(|number| number.parse::<u8>().ok())
+}
+
+fn is_quantity_char(character: char) -> bool {
+    character.is_ascii_digit() || matches!(character, '.' | ',' | '/') || unicode_fraction_value(character).is_some()
+}
+
+/// Converts a free text ingredient such as "2 c. à soupe d'huile d'olive" to an `Ingredient`.
+/// An ingredient without quantity (e.g. "sel") is counted once, as in our recipe files.
+pub fn parse_ingredient_text(text: &str) -> Option<Ingredient> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    // The quantity is made of the leading numbers, "1 1/2" being a single quantity
+    let mut quantity_end = 0;
+    for (index, character) in text.char_indices() {
+        if is_quantity_char(character) {
+            quantity_end = index + character.len_utf8();
+        } else if !(character == ' ' && text[index..].trim_start().starts_with(is_quantity_char)) {
+            break;
+        }
+    }
+    let quantity = parse_quantity(&text[..quantity_end]);
+    let rest = text[quantity_end..].trim_start();
+
+    let Some(quantity) = quantity else {
+        return Some(Ingredient {
+            name: text.to_string(),
+            unit: WHOLE_INGREDIENT.to_string(),
+            quantity: 1.0,
+        });
+    };
+
+    let lowercase_rest = rest.to_lowercase();
+    let unit = UNIT_ALIASES.iter().find(|(alias, _)| {
+        lowercase_rest.starts_with(alias)
+            && lowercase_rest[alias.len()..].starts_with(|character: char| character.is_whitespace() || character == '.')
+    });
+
+    let (unit, name) = match unit {
+        Some((alias, unit)) => (
+            unit.to_string(),
+            rest.get(alias.len()..).unwrap_or(rest).trim_start_matches('.').trim(),
+        ),
+        None => (WHOLE_INGREDIENT.to_string(), rest),
+    };
+    let name = ["de ", "d'", "d’"]
+        .iter()
+        .find_map(|article| name.strip_prefix(article))
+        .unwrap_or(name)
+        .trim();
+
+    Some(Ingredient {
+        name: name.to_string(),
+        unit,
+        quantity,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ingredient(quantity: f32, unit: &str, name: &str) -> Ingredient {
+        Ingredient { name: name.to_string(), unit: unit.to_string(), quantity }
+    }
+
+    #[test]
+    fn parses_free_text_ingredients() {
+        assert_eq!(parse_ingredient_text("2 c. à soupe d'huile d'olive"), Some(ingredient(2.0, "cs", "huile d'olive")));
+        assert_eq!(parse_ingredient_text("700 g de potimarron"), Some(ingredient(700.0, "g", "potimarron")));
+        assert_eq!(parse_ingredient_text("1 1/2 litre de lait"), Some(ingredient(1.5, "l", "lait")));
+        assert_eq!(parse_ingredient_text("½ oignon"), Some(ingredient(0.5, WHOLE_INGREDIENT, "oignon")));
+        assert_eq!(parse_ingredient_text("3 gousses d'ail"), Some(ingredient(3.0, WHOLE_INGREDIENT, "gousses d'ail")));
+        assert_eq!(parse_ingredient_text("sel"), Some(ingredient(1.0, WHOLE_INGREDIENT, "sel")));
+        assert_eq!(parse_ingredient_text("   "), None);
+    }
+
+    #[test]
+    fn imports_a_json_ld_recipe() {
+        let html = r#"<html><head>
+            <script type="application/ld+json">{"@type": "WebSite", "name": "Not a recipe"}</script>
+            <script type="application/ld+json">
+            {"@graph": [{"@type": "Recipe", "name": "Velouté de potimarron", "recipeYield": "4 servings",
+              "suitableForDiet": "https://schema.org/VegetarianDiet",
+              "recipeIngredient": ["700 g potimarron", "20 cl crème"],
+              "recipeInstructions": [{"@type": "HowToStep", "text": "Couper le potimarron"},
+                                     {"@type": "HowToStep", "text": "Mixer &amp; servir"}],
+              "prepTime": "PT15M", "cookTime": "PT1H", "keywords": "Soupe, Automne",
+              "author": {"@type": "Person", "name": "Marie"}}]}
+            </script></head></html>"#;
+        let recipe = import_from_json_ld(html).unwrap();
+        assert_eq!(recipe.name, "Velouté de potimarron");
+        assert_eq!(recipe.nbr_persons, 4);
+        assert!(recipe.is_veggie);
+        assert_eq!(recipe.ingredients, vec![ingredient(700.0, "g", "potimarron"), ingredient(20.0, "cl", "crème")]);
+        assert_eq!(recipe.steps, vec!["Couper le potimarron", "Mixer & servir"]);
+        assert_eq!(recipe.metadata.preparation_time, Some(15));
+        assert_eq!(recipe.metadata.cooking_time, Some(60));
+        assert_eq!(recipe.metadata.tags, vec!["soupe", "automne"]);
+        assert_eq!(recipe.metadata.author.as_deref(), Some("Marie"));
+    }
+
+    #[test]
+    fn rejects_pages_without_a_usable_json_ld_recipe() {
+        assert!(import_from_json_ld("<html><body>Pas de recette</body></html>").is_err());
+        let malformed = r#"<script type="application/ld+json">{"@type": "Recipe", "name": </script>"#;
+        assert!(import_from_json_ld(malformed).is_err());
+        let without_yield = r#"<script type="application/ld+json">{"@type": "Recipe", "name": "Tarte"}</script>"#;
+        assert!(import_from_json_ld(without_yield).is_err());
+        let unreadable_yield = r#"<script type="application/ld+json">{"@type": "Recipe", "recipeYield": "some"}</script>"#;
+        assert!(import_from_json_ld(unreadable_yield).is_err());
+    }
+
+    #[test]
+    fn imports_a_markdown_recipe() {
+        let markdown = "# Dahl de lentilles\nPour 4 personnes\nVeggie: oui\n\n## Ingrédients\n- 250 g lentilles corail\n* 1 oignon\n\n## Préparation\n1. Faire revenir l'oignon\n2) Ajouter les lentilles\n";
+        let recipe = import_from_markdown(markdown).unwrap();
+        assert_eq!(recipe.name, "Dahl de lentilles");
+        assert_eq!(recipe.nbr_persons, 4);
+        assert!(recipe.is_veggie);
+        assert_eq!(recipe.ingredients, vec![ingredient(250.0, "g", "lentilles corail"), ingredient(1.0, WHOLE_INGREDIENT, "oignon")]);
+        assert_eq!(recipe.steps, vec!["Faire revenir l'oignon", "Ajouter les lentilles"]);
+    }
+
+    #[test]
+    fn rejects_a_markdown_recipe_without_persons() {
+        assert!(import_from_markdown("# Dahl\n## Ingrédients\n- 250 g lentilles\n").is_err());
+        assert!(import_from_markdown("").is_err());
+    }
+
+    #[test]
+    fn imports_a_csv_recipe() {
+        let csv = "nom;Quiche lorraine\npersonnes;6 personnes\nveggie;non\nquantité;unité;ingrédient\n200;g;\"lardons; fumés\"\n3;;oeufs\n1;cs;crème fraîche";
+        let recipe = import_from_csv(csv, "quiche").unwrap();
+        assert_eq!(recipe.name, "Quiche lorraine");
+        assert_eq!(recipe.nbr_persons, 6);
+        assert!(!recipe.is_veggie);
+        assert_eq!(
+            recipe.ingredients,
+            vec![ingredient(200.0, "g", "lardons; fumés"), ingredient(3.0, WHOLE_INGREDIENT, "oeufs"), ingredient(1.0, "cs", "crème fraîche")]
+        );
+    }
+
+    #[test]
+    fn keeps_semicolons_inside_a_comma_separated_csv() {
+        let csv = "personnes,2\nnom,Salade; version été\n1,,concombre\n2,cs,vinaigrette; moutarde";
+        let recipe = import_from_csv(csv, "salade").unwrap();
+        assert_eq!(recipe.name, "Salade; version été");
+        assert_eq!(recipe.ingredients, vec![ingredient(1.0, WHOLE_INGREDIENT, "concombre"), ingredient(2.0, "cs", "vinaigrette; moutarde")]);
+    }
+
+    #[test]
+    fn a_csv_recipe_is_named_after_its_file_by_default() {
+        let recipe = import_from_csv("personnes;2\n1;citron", "tarte citron").unwrap();
+        assert_eq!(recipe.name, "tarte citron");
+        assert_eq!(recipe.ingredients, vec![ingredient(1.0, WHOLE_INGREDIENT, "citron")]);
+    }
+
+    #[test]
+    fn rejects_a_csv_recipe_without_persons() {
+        assert!(import_from_csv("200;g;farine", "pain").is_err());
+        assert!(import_from_csv("personnes;beaucoup\n200;g;farine", "pain").is_err());
+        assert!(import_from_csv("", "vide").is_err());
+    }
+}
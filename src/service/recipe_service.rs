@@ -1,29 +1,191 @@
-use crate::model::ingredient::{Ingredient, WHOLE_INGREDIENT};
+use crate::model::ingredient::{Ingredient, WHOLE_INGREDIENT, parse_quantity};
+use crate::model::language::Language;
 use crate::model::recipe::Recipe;
+use crate::model::recipe_file::RecipeFile;
+use crate::model::recipe_metadata::{Difficulty, parse_duration};
+use crate::model::recipe_slot::RecipeSlot;
+use crate::model::shopping_list::{ShoppingItemKey, ShoppingItemSource, ShoppingListEdits, ShoppingListLine};
+use crate::model::recipe_stats::{MAX_RATING_STARS, MIN_RATING_STARS, RecipeRating};
 use crate::repository::recipe_repository::RecipeRepository;
+use crate::repository::recipe_stats_repository::RecipeStatsRepository;
+use crate::service::import_service::import_recipe_file;
+use crate::service::search_service::{RecipeQuery, RecipeSortKey, compare_alphabetically};
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fs::File;
-use std::io;
 use std::io::Read;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-const RECIPE_DIRECTORY: &str = "recipes/";
+const RECIPE_EXTENSION__TXT: &str = "txt";
+const RECIPE_EXTENSION__TOML: &str = "toml";
 const RECIPE_PART__NAME: &str = "name";
 const RECIPE_PART__NBR_PERSONS: &str = "nbr_persons";
 const RECIPE_PART__VEGGIE: &str = "veggie";
 const RECIPE_PART__INGREDIENTS: &str = "ingredients";
 const RECIPE_PART__STEPS: &str = "steps";
 
+// Keys of the optional "key: value" lines following the veggie line
+const RECIPE_METADATA__VEGGIE: [&str; 2] = ["veggie", "végétarien"];
+const RECIPE_METADATA__PREPARATION_TIME: [&str; 2] = ["préparation", "preparation"];
+const RECIPE_METADATA__COOKING_TIME: [&str; 1] = ["cuisson"];
+const RECIPE_METADATA__DIFFICULTY: [&str; 2] = ["difficulté", "difficulte"];
+const RECIPE_METADATA__TAGS: [&str; 1] = ["tags"];
+const RECIPE_METADATA__CATEGORY: [&str; 2] = ["catégorie", "categorie"];
+const RECIPE_METADATA__SEASON: [&str; 1] = ["saison"];
+const RECIPE_METADATA__SOURCE: [&str; 1] = ["source"];
+const RECIPE_METADATA__AUTHOR: [&str; 1] = ["auteur"];
+
 const ACCEPTED_BOOLEAN__TRUE: [&str; 6] = ["true", "yes", "oui", "y", "o", "t"];
 const ACCEPTED_BOOLEAN__FALSE: [&str; 5] = ["false", "no", "non", "n", "f"];
 
+pub fn parse_boolean(str_boolean: &str) -> Option<bool> {
+    if ACCEPTED_BOOLEAN__TRUE.contains(&str_boolean) {
+        Some(true)
+    } else if ACCEPTED_BOOLEAN__FALSE.contains(&str_boolean) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Writes a recipe in the format read by `RecipeService::load_recipe`.
+pub fn recipe_to_txt(recipe: &Recipe) -> String {
+    // ':' separates the parts of an ingredient line, it can't be part of a name or a unit
+    let sanitize = |text: &str| text.replace(':', " -").trim().to_string();
+
+    let mut content = format!(
+        "{}\n\nNombre de personnes : {}\n\n",
+        recipe.name.trim(),
+        recipe.nbr_persons
+    );
+    content.push_str("# Accepted values :\n");
+    content.push_str(&format!("# Yes: \"{}\"\n", ACCEPTED_BOOLEAN__TRUE.join("\", \"")));
+    content.push_str(&format!("# No: \"{}\"\n", ACCEPTED_BOOLEAN__FALSE.join("\", \"")));
+    content.push_str(&format!("Veggie: {}\n", if recipe.is_veggie { "oui" } else { "non" }));
+    let metadata = &recipe.metadata;
+    if let Some(preparation_time) = metadata.preparation_time {
+        content.push_str(&format!("Préparation: {preparation_time} min\n"));
+    }
+    if let Some(cooking_time) = metadata.cooking_time {
+        content.push_str(&format!("Cuisson: {cooking_time} min\n"));
+    }
+    if let Some(difficulty) = metadata.difficulty {
+        content.push_str(&format!("Difficulté: {}\n", difficulty.label(Language::French).to_lowercase()));
+    }
+    if !metadata.tags.is_empty() {
+        content.push_str(&format!("Tags: {}\n", metadata.tags.join(", ")));
+    }
+    if let Some(category) = &metadata.category {
+        content.push_str(&format!("Catégorie: {category}\n"));
+    }
+    if let Some(season) = &metadata.season {
+        content.push_str(&format!("Saison: {season}\n"));
+    }
+    if let Some(source) = &metadata.source {
+        content.push_str(&format!("Source: {source}\n"));
+    }
+    if let Some(author) = &metadata.author {
+        content.push_str(&format!("Auteur: {author}\n"));
+    }
+    content.push('\n');
+
+    for ingredient in &recipe.ingredients {
+        if ingredient.unit == WHOLE_INGREDIENT {
+            content.push_str(&format!("{} : {}\n", ingredient.quantity, sanitize(&ingredient.name)));
+        } else {
+            content.push_str(&format!(
+                "{} : {} : {}\n",
+                ingredient.quantity,
+                sanitize(&ingredient.unit),
+                sanitize(&ingredient.name)
+            ));
+        }
+    }
+    content.push('\n');
+
+    for step in recipe.steps.iter().flat_map(|step| step.lines()) {
+        if !step.trim().is_empty() {
+            content.push_str(&format!("{}\n", step.trim()));
+        }
+    }
+    content
+}
+
+/// Applies a "key: value" line of the recipe header (veggie flag, times, tags, ...).
+pub fn apply_recipe_metadata_line(recipe: &mut Recipe, key: &str, value: &str) {
+    let key = key.trim().to_lowercase();
+    let key = key.as_str();
+    let value = value.trim();
+    let optional_value = (!value.is_empty()).then(|| value.to_string());
+
+    if RECIPE_METADATA__VEGGIE.contains(&key) {
+        if let Some(is_veggie) = parse_boolean(&value.to_lowercase()) {
+            recipe.is_veggie = is_veggie;
+        }
+    } else if RECIPE_METADATA__PREPARATION_TIME.contains(&key) {
+        recipe.metadata.preparation_time = parse_duration(value);
+    } else if RECIPE_METADATA__COOKING_TIME.contains(&key) {
+        recipe.metadata.cooking_time = parse_duration(value);
+    } else if RECIPE_METADATA__DIFFICULTY.contains(&key) {
+        recipe.metadata.difficulty = Difficulty::parse(value);
+    } else if RECIPE_METADATA__TAGS.contains(&key) {
+        recipe.metadata.tags = value
+            .split(',')
+            .map(|tag| tag.trim().to_lowercase())
+            .filter(|tag| !tag.is_empty())
+            .collect();
+    } else if RECIPE_METADATA__CATEGORY.contains(&key) {
+        recipe.metadata.category = optional_value;
+    } else if RECIPE_METADATA__SEASON.contains(&key) {
+        recipe.metadata.season = optional_value;
+    } else if RECIPE_METADATA__SOURCE.contains(&key) {
+        recipe.metadata.source = optional_value;
+    } else if RECIPE_METADATA__AUTHOR.contains(&key) {
+        recipe.metadata.author = optional_value;
+    }
+}
+
+// The best rated recipe comes first, recipes without rating come last
+fn compare_ratings(first_rating: Option<f32>, second_rating: Option<f32>) -> Ordering {
+    match (first_rating, second_rating) {
+        (Some(first_rating), Some(second_rating)) => second_rating.total_cmp(&first_rating),
+        (first_rating, second_rating) => second_rating.is_some().cmp(&first_rating.is_some()),
+    }
+}
+
+fn recipe_file_name(recipe_name: &str) -> String {
+    let file_name: String = recipe_name
+        .trim()
+        .chars()
+        .map(|character| if character.is_alphanumeric() { character } else { '_' })
+        .collect();
+    format!("{}.txt", file_name.trim_matches('_'))
+}
+
 pub struct RecipeService {
     pub recipe_repository: RecipeRepository,
+    pub recipe_stats_repository: RecipeStatsRepository,
+    // Why the statistics of the previous sessions couldn't be loaded
+    pub recipe_stats_error: Option<String>,
+    recipes_directory: PathBuf,
 }
 
 impl RecipeService {
-    pub fn new() -> RecipeService {
+    /// Service for the recipes of `recipes_directory`, see `Settings::recipes_directory`.
+    pub fn new(recipes_directory: &str) -> RecipeService {
+        let (recipe_stats_repository, recipe_stats_error) = match RecipeStatsRepository::load() {
+            Ok(recipe_stats_repository) => (recipe_stats_repository, None),
+            Err(error) => {
+                let (recipe_stats_repository, error) = RecipeStatsRepository::recover(error);
+                (recipe_stats_repository, Some(error))
+            }
+        };
         RecipeService {
             recipe_repository: RecipeRepository::new(),
+            recipe_stats_repository,
+            recipe_stats_error,
+            recipes_directory: PathBuf::from(recipes_directory),
         }
     }
 
@@ -56,10 +218,105 @@ impl RecipeService {
         ingredients
     }
 
-    pub fn load_recipe(&mut self, recipe_file: &Path) -> Recipe {
-        let mut file = File::open(recipe_file).expect(format!("Failed to open recipe file : {}", recipe_file.display()).as_str());
+    /// Shopping list of the planned recipes, with the slots each line is needed for and the
+    /// edits made by hand : items ticked off, quantities changed and items added.
+    pub fn shopping_list(
+        &self,
+        selected_recipes: &HashMap<RecipeSlot, Recipe>,
+        shopping_list_edits: &ShoppingListEdits,
+    ) -> Vec<ShoppingListLine> {
+        let mut planned_slots: Vec<RecipeSlot> = selected_recipes.keys().copied().collect();
+        planned_slots.sort_by_key(|recipe_slot| (recipe_slot.day.offset(), recipe_slot.meal.position()));
+        let synced_recipes: Vec<(RecipeSlot, Recipe)> = planned_slots
+            .into_iter()
+            .map(|recipe_slot| {
+                let mut recipe = selected_recipes[&recipe_slot].clone();
+                recipe.sync_with_configured_nbr_persons();
+                (recipe_slot, recipe)
+            })
+            .collect();
+        // In the order of the week too, so that the lines come in the same order at each render
+        // (`gather_all_ingredients_from_recipes_vector` scales the recipes itself)
+        let all_recipes: Vec<Recipe> =
+            synced_recipes.iter().map(|(recipe_slot, _)| selected_recipes[recipe_slot].clone()).collect();
+
+        // Each recipe needing the ingredient, with the quantity it needs for its persons
+        let sources_of = |key: &ShoppingItemKey| -> Vec<ShoppingItemSource> {
+            synced_recipes
+                .iter()
+                .filter_map(|(recipe_slot, recipe)| {
+                    let used_ingredients = recipe.ingredients.iter().filter(|used| ShoppingItemKey::of(used) == *key);
+                    let quantities: Vec<f32> = used_ingredients.map(|used| used.quantity).collect();
+                    (!quantities.is_empty()).then(|| ShoppingItemSource::Recipe {
+                        recipe_slot: *recipe_slot,
+                        recipe_name: recipe.name.clone(),
+                        quantity: quantities.iter().sum(),
+                    })
+                })
+                .collect()
+        };
+        let mut shopping_list: Vec<ShoppingListLine> = self
+            .gather_all_ingredients_from_recipes_vector(&all_recipes)
+            .into_iter()
+            .map(|ingredient| ShoppingListLine {
+                sources: sources_of(&ShoppingItemKey::of(&ingredient)),
+                ingredient,
+                is_ticked: false,
+                is_edited: false,
+            })
+            .collect();
+
+        for manual_item in shopping_list_edits.manual_items.iter() {
+            let key = ShoppingItemKey::of(manual_item);
+            let manual_source = ShoppingItemSource::Manual { quantity: manual_item.quantity };
+            match shopping_list.iter_mut().find(|line| line.key() == key) {
+                Some(line) => {
+                    line.ingredient.quantity += manual_item.quantity;
+                    line.sources.push(manual_source);
+                }
+                None => shopping_list.push(ShoppingListLine {
+                    ingredient: manual_item.clone(),
+                    sources: vec![manual_source],
+                    is_ticked: false,
+                    is_edited: false,
+                }),
+            }
+        }
+
+        for line in shopping_list.iter_mut() {
+            let key = line.key();
+            if let Some(edited_quantity) = shopping_list_edits.edited_quantities.get(&key) {
+                line.ingredient.quantity = *edited_quantity;
+                line.is_edited = true;
+            }
+            line.is_ticked = shopping_list_edits.ticked_items.contains(&key);
+        }
+        shopping_list
+    }
+
+    /// Loads a recipe file, the format being chosen from its extension :
+    /// `.toml` for structured recipes, anything else for the legacy `.txt` format.
+    pub fn load_recipe(&mut self, recipe_file: &Path) -> Result<Recipe, String> {
+        match recipe_file.extension().and_then(|extension| extension.to_str()) {
+            Some(RECIPE_EXTENSION__TOML) => self.load_recipe_from_toml(recipe_file),
+            _ => self.load_recipe_from_txt(recipe_file),
+        }
+    }
+
+    fn load_recipe_from_toml(&mut self, recipe_file: &Path) -> Result<Recipe, String> {
+        let content = std::fs::read_to_string(recipe_file)
+            .map_err(|error| format!("Failed to read recipe file {} : {}", recipe_file.display(), error))?;
+        let recipe_file_content: RecipeFile = toml::from_str(&content)
+            .map_err(|error| format!("Failed to load recipe file {} : {}", recipe_file.display(), error))?;
+        Ok(recipe_file_content.into_recipe())
+    }
+
+    fn load_recipe_from_txt(&mut self, recipe_file: &Path) -> Result<Recipe, String> {
+        let mut file = File::open(recipe_file)
+            .map_err(|error| format!("Failed to open recipe file {} : {}", recipe_file.display(), error))?;
         let mut content = String::new();
-        file.read_to_string(&mut content).expect(format!("Failed to read recipe file : {}", recipe_file.display()).as_str());
+        file.read_to_string(&mut content)
+            .map_err(|error| format!("Failed to read recipe file {} : {}", recipe_file.display(), error))?;
         let recipe_lines: Vec<&str> = content.split("\n").collect();
 
         let recipe_pattern = [
@@ -89,21 +346,18 @@ impl RecipeService {
 
             match position_in_pattern {
                 RECIPE_PART__NAME => {
-                    recipe.set_name(recipe_lines[line_number].to_string());
+                    recipe.set_name(current_line.to_string());
                 }
                 RECIPE_PART__NBR_PERSONS => {
                     let line_parts: Vec<&str> = current_line.split_whitespace().collect();
                     let nbr_persons_as_str = line_parts[line_parts.len() - 1].trim();
-                    recipe.nbr_persons = nbr_persons_as_str.parse::<u8>().expect(format!("Failed to load the number of person this recipe is for from line \"{}\" in file {}", current_line, recipe_file.display()).as_str());
-                    recipe.configured_nbr_persons = recipe.nbr_persons.clone();
+                    recipe.nbr_persons = nbr_persons_as_str.parse::<u8>().map_err(|_| format!("Failed to load the number of person this recipe is for from line \"{}\" in file {}", current_line, recipe_file.display()))?;
+                    recipe.reset_configured_nbr_persons();
                 }
                 RECIPE_PART__VEGGIE => {
-                    let veggie_parts: Vec<&str> = current_line.split_whitespace().collect();
-                    let str_boolean = veggie_parts[1].trim();
-                    if ACCEPTED_BOOLEAN__TRUE.contains(&str_boolean) {
-                        recipe.is_veggie = true;
-                    } else if ACCEPTED_BOOLEAN__FALSE.contains(&str_boolean) {
-                        recipe.is_veggie = false;
+                    // The veggie line may be followed by optional metadata, one "key: value" per line
+                    if let Some((key, value)) = current_line.split_once(':') {
+                        apply_recipe_metadata_line(&mut recipe, key, value);
                     }
                 }
                 RECIPE_PART__INGREDIENTS => {
@@ -116,12 +370,12 @@ impl RecipeService {
 
                     match ingredient_line_parts.len() {
                         3 => {
-                            ingredient_quantity = ingredient_line_parts[0].trim().parse::<f32>().expect(format!("Failed to load the ingredient from line \"{}\" in file {}", current_line, recipe_file.display()).as_str());
+                            ingredient_quantity = parse_quantity(ingredient_line_parts[0]).ok_or_else(|| format!("Failed to load the ingredient from line \"{}\" in file {}", current_line, recipe_file.display()))?;
                             ingredient_unit = ingredient_line_parts[1].trim().to_string();
                             ingredient_name = ingredient_line_parts[2].trim().to_string();
                         }
                         2 => {
-                            ingredient_quantity = ingredient_line_parts[0].trim().parse::<f32>().expect(format!("Failed to load the ingredient from line \"{}\" in file {}", current_line, recipe_file.display()).as_str());
+                            ingredient_quantity = parse_quantity(ingredient_line_parts[0]).ok_or_else(|| format!("Failed to load the ingredient from line \"{}\" in file {}", current_line, recipe_file.display()))?;
                             ingredient_unit = WHOLE_INGREDIENT.to_string();
                             ingredient_name = ingredient_line_parts[1].trim().to_string();
                         }
@@ -142,49 +396,143 @@ impl RecipeService {
                 }
             }
         }
-        recipe
+        Ok(recipe)
     }
 
-    pub fn load_all_recipes(&mut self) {
-        let paths = std::fs::read_dir(RECIPE_DIRECTORY).unwrap();
-        for path in paths {
-            let path = path.unwrap();
-            if path.file_type().unwrap().is_file()
-                && path.file_name().into_string().unwrap().ends_with(".txt")
+    /// Loads the recipes of the recipes directory. A file that can't be read is skipped, the
+    /// returned errors telling which ones, so that a single malformed recipe doesn't hide the others.
+    pub fn load_all_recipes(&mut self) -> Result<Vec<String>, String> {
+        let paths = std::fs::read_dir(&self.recipes_directory)
+            .map_err(|error| format!("Failed to read {} : {}", self.recipes_directory.display(), error))?;
+        let mut loading_errors = Vec::new();
+        for path in paths.flatten() {
+            let extension = path.path().extension().and_then(|extension| extension.to_str()).map(str::to_string);
+            if path.file_type().is_ok_and(|file_type| file_type.is_file())
+                && matches!(extension.as_deref(), Some(RECIPE_EXTENSION__TXT | RECIPE_EXTENSION__TOML))
             {
-                let recipe = self.load_recipe(path.path().as_path());
-                self.recipe_repository.add_recipe(recipe);
+                match self.load_recipe(path.path().as_path()) {
+                    Ok(recipe) => self.recipe_repository.add_recipe(recipe),
+                    Err(error) => loading_errors.push(error),
+                }
             }
         }
+        Ok(loading_errors)
     }
 
-    pub fn find_recipe_by_name(&self, recipe_name: &String) -> Option<Recipe> {
-        self.recipe_repository.get_recipe(recipe_name)
+    /// Converts a recipe coming from another format (see `import_service`), saves it as a
+    /// `.txt` file in the recipes directory and makes it available for selection.
+    pub fn import_recipe(&mut self, file_to_import: &Path) -> Result<PathBuf, String> {
+        let recipe = import_recipe_file(file_to_import)?;
+        if self.recipe_repository.get_recipe(&recipe.name).is_some() {
+            return Err(format!("A recipe named \"{}\" already exists", recipe.name));
+        }
+
+        let recipe_file = self.recipes_directory.join(recipe_file_name(&recipe.name));
+        if recipe_file.exists() {
+            return Err(format!("The file {} already exists", recipe_file.display()));
+        }
+        std::fs::write(&recipe_file, recipe_to_txt(&recipe))
+            .map_err(|error| format!("Failed to write recipe file {} : {}", recipe_file.display(), error))?;
+
+        let saved_recipe = self.load_recipe(&recipe_file)?;
+        self.recipe_repository.add_recipe(saved_recipe);
+        Ok(recipe_file)
     }
 
-    pub fn list_recipes(&self, filter: String, only_veggies: bool) -> Vec<String> {
-        let mut recipe_list: Vec<String> = Vec::new();
-        match only_veggies {
-            true => {
-                let all_recipes_names = self.recipe_repository.list_all_recipes_names();
-                for recipe_name in all_recipes_names {
-                    if self.recipe_repository.get_recipe(&recipe_name).unwrap().is_veggie {
-                        recipe_list.push(recipe_name.to_string());
-                    }
-                }
-            }
-            false => recipe_list = self.recipe_repository.list_all_recipes_names(),
+    /// Rewrites a legacy `.txt` recipe in the `.toml` format. The `.txt` file is only removed
+    /// once the new file has been read back and gives exactly the same recipe.
+    pub fn convert_recipe_to_toml(&mut self, txt_recipe_file: &Path) -> Result<PathBuf, String> {
+        if txt_recipe_file.extension().and_then(|extension| extension.to_str()) != Some(RECIPE_EXTENSION__TXT) {
+            return Err(format!("{} is not a .txt recipe", txt_recipe_file.display()));
         }
-        if filter != "" {
-            recipe_list = recipe_list
-                .into_iter()
-                .filter(|recipe_name| {
-                    recipe_name
-                        .to_lowercase()
-                        .contains(filter.to_lowercase().as_str())
-                })
-                .collect();
+        let toml_recipe_file = txt_recipe_file.with_extension(RECIPE_EXTENSION__TOML);
+        if toml_recipe_file.exists() {
+            return Err(format!("The file {} already exists", toml_recipe_file.display()));
         }
-        recipe_list
+
+        let recipe = self.load_recipe_from_txt(txt_recipe_file)?;
+        let content = toml::to_string_pretty(&RecipeFile::from(&recipe))
+            .map_err(|error| format!("Failed to convert {} : {}", txt_recipe_file.display(), error))?;
+        std::fs::write(&toml_recipe_file, content)
+            .map_err(|error| format!("Failed to write recipe file {} : {}", toml_recipe_file.display(), error))?;
+
+        if self.load_recipe_from_toml(&toml_recipe_file).as_ref() != Ok(&recipe) {
+            let _ = std::fs::remove_file(&toml_recipe_file);
+            return Err(format!("The conversion of {} would lose data, the file was kept as is", txt_recipe_file.display()));
+        }
+        std::fs::remove_file(txt_recipe_file)
+            .map_err(|error| format!("Failed to remove {} : {}", txt_recipe_file.display(), error))?;
+        Ok(toml_recipe_file)
+    }
+
+    pub fn find_recipe_by_name(&self, recipe_name: &String) -> Option<Recipe> {
+        self.recipe_repository.get_recipe(recipe_name)
+    }
+
+    pub fn list_recipes(&self, filter: String, only_veggies: bool, only_favourites: bool, sort_key: RecipeSortKey) -> Vec<String> {
+        let mut query = RecipeQuery::parse(&filter);
+        query.only_veggies = only_veggies;
+        self.search_recipes(&query, sort_key)
+            .into_iter()
+            .filter(|recipe_name| !only_favourites || self.recipe_stats_repository.get_stats(recipe_name).is_favourite)
+            .collect()
+    }
+
+    /// Names of the recipes matching the query, the most relevant first, then following `sort_key`.
+    pub fn search_recipes(&self, query: &RecipeQuery, sort_key: RecipeSortKey) -> Vec<String> {
+        let mut matching_recipes: Vec<(u32, String)> = self
+            .recipe_repository
+            .list_all_recipes_names()
+            .into_iter()
+            .filter_map(|recipe_name| {
+                let recipe = self.recipe_repository.get_recipe(&recipe_name)?;
+                query.score(&recipe).map(|score| (score, recipe_name))
+            })
+            .collect();
+        matching_recipes.sort_by(|(first_score, first_name), (second_score, second_name)| {
+            second_score
+                .cmp(first_score)
+                .then_with(|| self.compare_recipes(first_name, second_name, sort_key))
+        });
+        matching_recipes.into_iter().map(|(_, recipe_name)| recipe_name).collect()
+    }
+
+    fn compare_recipes(&self, first_recipe_name: &str, second_recipe_name: &str, sort_key: RecipeSortKey) -> Ordering {
+        let first_stats = self.recipe_stats_repository.get_stats(first_recipe_name);
+        let second_stats = self.recipe_stats_repository.get_stats(second_recipe_name);
+        let ordering = match sort_key {
+            RecipeSortKey::Alphabetical => Ordering::Equal,
+            RecipeSortKey::RecentlyUsed => second_stats.last_planned.cmp(&first_stats.last_planned),
+            RecipeSortKey::MostPlanned => second_stats.times_planned.cmp(&first_stats.times_planned),
+            RecipeSortKey::FavouritesFirst => second_stats
+                .is_favourite
+                .cmp(&first_stats.is_favourite)
+                .then_with(|| compare_ratings(first_stats.average_rating(), second_stats.average_rating())),
+            RecipeSortKey::BestRated => compare_ratings(first_stats.average_rating(), second_stats.average_rating()),
+        };
+        ordering.then_with(|| compare_alphabetically(first_recipe_name, second_recipe_name))
+    }
+
+    pub fn toggle_favourite_recipe(&mut self, recipe_name: &str) -> Result<(), String> {
+        self.recipe_stats_repository
+            .update_stats(recipe_name, |stats| stats.is_favourite = !stats.is_favourite)
+    }
+
+    pub fn rate_recipe(&mut self, recipe_name: &str, stars: u8, note: String) -> Result<(), String> {
+        let rating = RecipeRating {
+            stars: stars.clamp(MIN_RATING_STARS, MAX_RATING_STARS),
+            note: note.trim().to_string(),
+            rated_at: SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).ok(),
+        };
+        self.recipe_stats_repository.update_stats(recipe_name, |stats| stats.ratings.push(rating))
+    }
+
+    /// Keeps track of the recipes put in the plan, for the "recently used" and "most planned" orders.
+    pub fn record_recipe_planned(&mut self, recipe_name: &str) -> Result<(), String> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).ok();
+        self.recipe_stats_repository.update_stats(recipe_name, |stats| {
+            stats.times_planned += 1;
+            stats.last_planned = now;
+        })
     }
 }
@@ -0,0 +1,320 @@
+use crate::model::language::Language;
+use crate::model::recipe::Recipe;
+use crate::model::recipe_metadata::{Difficulty, parse_duration};
+use std::cmp::Ordering;
+
+const QUERY_PREFIX__REQUIRED_INGREDIENT: char = '+';
+const QUERY_PREFIX__EXCLUDED_INGREDIENT: char = '-';
+const QUERY_PREFIX__TAG: char = '#';
+const QUERY_PREFIX__MAX_TIME: char = '<';
+const QUERY_KEY__DIFFICULTY: &str = "difficulte:";
+const QUERY_KEY__SEASON: &str = "saison:";
+const QUERY_KEY__CATEGORY: &str = "categorie:";
+
+// Weights used to rank the recipes, a match on the name being worth more than a match on an ingredient
+const SCORE__NAME_EXACT_WORD: u32 = 10;
+const SCORE__NAME_WORD_PREFIX: u32 = 8;
+const SCORE__NAME_SUBSTRING: u32 = 6;
+const SCORE__NAME_FUZZY: u32 = 4;
+const SCORE__INGREDIENT_WORD: u32 = 3;
+const SCORE__INGREDIENT_FUZZY: u32 = 2;
+
+/// Order of the recipes having the same relevance for the current search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecipeSortKey {
+    #[default]
+    Alphabetical,
+    RecentlyUsed,
+    MostPlanned,
+    FavouritesFirst,
+    BestRated,
+}
+
+impl RecipeSortKey {
+    pub const ALL: [RecipeSortKey; 5] = [
+        RecipeSortKey::Alphabetical,
+        RecipeSortKey::RecentlyUsed,
+        RecipeSortKey::MostPlanned,
+        RecipeSortKey::FavouritesFirst,
+        RecipeSortKey::BestRated,
+    ];
+
+    pub fn label(&self, language: Language) -> &'static str {
+        language.texts().sort_keys[RecipeSortKey::ALL.iter().position(|sort_key| sort_key == self).unwrap()]
+    }
+}
+
+/// Search criteria on recipes, usually parsed from the text typed in the search bar.
+/// Words are matched against the name then the ingredients, ignoring case and accents,
+/// and tolerating a typo or two.
+#[derive(Debug, Default, Clone)]
+pub struct RecipeQuery {
+    pub terms: Vec<String>,
+    pub required_ingredients: Vec<String>,
+    pub excluded_ingredients: Vec<String>,
+    pub tags: Vec<String>,
+    pub max_total_time: Option<u16>,
+    pub difficulty: Option<Difficulty>,
+    pub season: Option<String>,
+    pub category: Option<String>,
+    pub only_veggies: bool,
+}
+
+impl RecipeQuery {
+    /// Parses a query such as `poulet -porc +butternut #rapide <30 saison:automne categorie:plat`.
+    pub fn parse(text: &str) -> RecipeQuery {
+        let mut query = RecipeQuery::default();
+        for word in text.split_whitespace() {
+            let normalized_word = normalize_text(word);
+            if let Some(ingredient) = word.strip_prefix(QUERY_PREFIX__REQUIRED_INGREDIENT) {
+                push_if_not_empty(&mut query.required_ingredients, ingredient);
+            } else if let Some(ingredient) = word.strip_prefix(QUERY_PREFIX__EXCLUDED_INGREDIENT) {
+                push_if_not_empty(&mut query.excluded_ingredients, ingredient);
+            } else if let Some(tag) = word.strip_prefix(QUERY_PREFIX__TAG) {
+                push_if_not_empty(&mut query.tags, tag);
+            } else if let Some(max_time) = word.strip_prefix(QUERY_PREFIX__MAX_TIME) {
+                query.max_total_time = parse_duration(max_time.trim_start_matches('='));
+            } else if let Some(difficulty) = normalized_word.strip_prefix(QUERY_KEY__DIFFICULTY) {
+                query.difficulty = Difficulty::parse(difficulty);
+            } else if let Some(season) = normalized_word.strip_prefix(QUERY_KEY__SEASON) {
+                query.season = Some(season.to_string());
+            } else if let Some(category) = normalized_word.strip_prefix(QUERY_KEY__CATEGORY) {
+                query.category = Some(category.to_string());
+            } else {
+                push_if_not_empty(&mut query.terms, word);
+            }
+        }
+        query
+    }
+
+    /// Returns the relevance of the recipe for this query, or None if the recipe does not match.
+    pub fn score(&self, recipe: &Recipe) -> Option<u32> {
+        if self.only_veggies && !recipe.is_veggie {
+            return None;
+        }
+        let metadata = &recipe.metadata;
+        if let Some(max_total_time) = self.max_total_time {
+            // A recipe without any time can't be said to be quick enough
+            let total_time = match (metadata.preparation_time, metadata.cooking_time) {
+                (None, None) => return None,
+                (preparation_time, cooking_time) => preparation_time.unwrap_or(0).saturating_add(cooking_time.unwrap_or(0)),
+            };
+            if total_time > max_total_time {
+                return None;
+            }
+        }
+        if self.difficulty.is_some() && metadata.difficulty != self.difficulty {
+            return None;
+        }
+        if let Some(season) = &self.season {
+            let recipe_season = metadata.season.as_deref().map(normalize_text).unwrap_or_default();
+            if !recipe_season.contains(season.as_str()) {
+                return None;
+            }
+        }
+        if let Some(category) = &self.category {
+            let recipe_category = metadata.category.as_deref().map(normalize_text).unwrap_or_default();
+            if !recipe_category.contains(category.as_str()) {
+                return None;
+            }
+        }
+        let recipe_tags: Vec<String> = metadata.tags.iter().map(|tag| normalize_text(tag)).collect();
+        if !self.tags.iter().all(|tag| recipe_tags.contains(&normalize_text(tag))) {
+            return None;
+        }
+
+        let ingredients_words: Vec<String> = recipe
+            .ingredients
+            .iter()
+            .flat_map(|ingredient| words_of(&ingredient.name))
+            .collect();
+        if self
+            .excluded_ingredients
+            .iter()
+            .any(|excluded| ingredients_words.iter().any(|word| is_same_word(&normalize_text(excluded), word)))
+        {
+            return None;
+        }
+        if !self
+            .required_ingredients
+            .iter()
+            .all(|required| ingredients_words.iter().any(|word| word_matches(&normalize_text(required), word)))
+        {
+            return None;
+        }
+
+        let normalized_name = normalize_text(&recipe.name);
+        let name_words = words_of(&recipe.name);
+        let mut score = 0;
+        for term in self.terms.iter().map(|term| normalize_text(term)) {
+            score += score_term(&term, &normalized_name, &name_words, &ingredients_words)?;
+        }
+        Some(score)
+    }
+}
+
+fn push_if_not_empty(list: &mut Vec<String>, value: &str) {
+    if !value.is_empty() {
+        list.push(value.to_string());
+    }
+}
+
+fn score_term(term: &str, normalized_name: &str, name_words: &[String], ingredients_words: &[String]) -> Option<u32> {
+    if name_words.iter().any(|word| word == term) {
+        Some(SCORE__NAME_EXACT_WORD)
+    } else if name_words.iter().any(|word| word.starts_with(term)) {
+        Some(SCORE__NAME_WORD_PREFIX)
+    } else if normalized_name.contains(term) {
+        Some(SCORE__NAME_SUBSTRING)
+    } else if name_words.iter().any(|word| is_fuzzy_match(term, word)) {
+        Some(SCORE__NAME_FUZZY)
+    } else if ingredients_words.iter().any(|word| word.starts_with(term)) {
+        Some(SCORE__INGREDIENT_WORD)
+    } else if ingredients_words.iter().any(|word| is_fuzzy_match(term, word)) {
+        Some(SCORE__INGREDIENT_FUZZY)
+    } else {
+        None
+    }
+}
+
+// An ingredient criterion matches a word it starts ("poulet" matches "poulets"), or with a typo
+fn word_matches(criterion: &str, word: &str) -> bool {
+    word.starts_with(criterion) || is_fuzzy_match(criterion, word)
+}
+
+// An excluded ingredient only removes the recipes having that very word, singular or plural,
+// so that excluding "lait" keeps the recipes with "laitue"
+fn is_same_word(criterion: &str, word: &str) -> bool {
+    let singular = |word: &str| word.strip_suffix(['s', 'x']).unwrap_or(word).to_string();
+    singular(criterion) == singular(word)
+}
+
+fn is_fuzzy_match(term: &str, word: &str) -> bool {
+    let allowed_typos = match term.chars().count() {
+        0..=3 => 0,
+        4..=6 => 1,
+        _ => 2,
+    };
+    allowed_typos > 0 && levenshtein_distance(term, word) <= allowed_typos
+}
+
+fn levenshtein_distance(first: &str, second: &str) -> usize {
+    let second_chars: Vec<char> = second.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=second_chars.len()).collect();
+
+    for (i, first_char) in first.chars().enumerate() {
+        let mut current_row = vec![i + 1; second_chars.len() + 1];
+        for (j, second_char) in second_chars.iter().enumerate() {
+            let substitution_cost = if first_char == *second_char { 0 } else { 1 };
+            current_row[j + 1] = (previous_row[j] + substitution_cost)
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+        }
+        previous_row = current_row;
+    }
+    previous_row[second_chars.len()]
+}
+
+fn words_of(text: &str) -> Vec<String> {
+    normalize_text(text)
+        .split(|character: char| !character.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Alphabetical order following the French usage : accents and case only matter
+/// to separate words that are otherwise equal ("cote" < "côte" < "Côte" < "coter").
+pub fn compare_alphabetically(first: &str, second: &str) -> Ordering {
+    normalize_text(first)
+        .cmp(&normalize_text(second))
+        .then_with(|| first.to_lowercase().cmp(&second.to_lowercase()))
+        .then_with(|| second.cmp(first))
+}
+
+/// Lowercases a text and removes its accents, so that "Velouté" and "veloute" are equal.
+pub fn normalize_text(text: &str) -> String {
+    let mut normalized_text = String::with_capacity(text.len());
+    for character in text.chars().flat_map(char::to_lowercase) {
+        match character {
+            'à' | 'â' | 'ä' | 'á' | 'ã' => normalized_text.push('a'),
+            'ç' => normalized_text.push('c'),
+            'é' | 'è' | 'ê' | 'ë' => normalized_text.push('e'),
+            'î' | 'ï' | 'í' | 'ì' => normalized_text.push('i'),
+            'ô' | 'ö' | 'ó' | 'ò' | 'õ' => normalized_text.push('o'),
+            'ù' | 'û' | 'ü' | 'ú' => normalized_text.push('u'),
+            'ÿ' => normalized_text.push('y'),
+            'ñ' => normalized_text.push('n'),
+            'œ' => normalized_text.push_str("oe"),
+            'æ' => normalized_text.push_str("ae"),
+            '’' => normalized_text.push('\''),
+            _ => normalized_text.push(character),
+        }
+    }
+    normalized_text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ingredient::Ingredient;
+
+    fn recipe(name: &str, ingredient_names: &[&str]) -> Recipe {
+        let mut recipe = Recipe::new();
+        recipe.set_name(name.to_string());
+        for ingredient_name in ingredient_names {
+            recipe.add_ingredient(Ingredient {
+                name: ingredient_name.to_string(),
+                unit: "g".to_string(),
+                quantity: 100.0,
+            });
+        }
+        recipe
+    }
+
+    #[test]
+    fn parses_every_kind_of_criterion() {
+        let query = RecipeQuery::parse("Poulet -porc +butternut #Rapide <1h30 saison:automne categorie:Plat difficulte:facile");
+        assert_eq!(query.terms, vec!["Poulet"]);
+        assert_eq!(query.excluded_ingredients, vec!["porc"]);
+        assert_eq!(query.required_ingredients, vec!["butternut"]);
+        assert_eq!(query.tags, vec!["Rapide"]);
+        assert_eq!(query.max_total_time, Some(90));
+        assert_eq!(query.season.as_deref(), Some("automne"));
+        assert_eq!(query.category.as_deref(), Some("plat"));
+        assert_eq!(query.difficulty, Some(Difficulty::Easy));
+        assert!(RecipeQuery::parse("- + #").terms.is_empty());
+    }
+
+    #[test]
+    fn ranks_name_matches_above_ingredient_matches() {
+        let query = RecipeQuery::parse("poulet");
+        let exact_word = query.score(&recipe("Poulet rôti", &[])).unwrap();
+        let prefix = query.score(&recipe("Poulets grillés", &[])).unwrap();
+        let typo = RecipeQuery::parse("poulat").score(&recipe("Poulet rôti", &[])).unwrap();
+        let ingredient = query.score(&recipe("Curry", &["blanc de poulet"])).unwrap();
+        assert!(exact_word > prefix && prefix > typo && typo > ingredient);
+        assert_eq!(RecipeQuery::parse("velouté").score(&recipe("Veloute de potimarron", &[])), Some(SCORE__NAME_EXACT_WORD));
+        assert_eq!(query.score(&recipe("Dahl", &["lentilles"])), None);
+    }
+
+    #[test]
+    fn excludes_only_the_very_ingredient() {
+        let query = RecipeQuery::parse("-lait -oeuf");
+        assert!(query.score(&recipe("Salade", &["laitue"])).is_some());
+        assert!(query.score(&recipe("Riz au lait", &["lait entier"])).is_none());
+        assert!(query.score(&recipe("Omelette", &["œufs"])).is_none());
+    }
+
+    #[test]
+    fn filters_on_category_and_total_time() {
+        let mut dessert = recipe("Tarte aux pommes", &["pommes"]);
+        dessert.metadata.category = Some("Dessert".to_string());
+        dessert.metadata.preparation_time = Some(u16::MAX);
+        dessert.metadata.cooking_time = Some(40);
+        assert!(RecipeQuery::parse("categorie:dessert").score(&dessert).is_some());
+        assert!(RecipeQuery::parse("categorie:plat").score(&dessert).is_none());
+        assert!(RecipeQuery::parse("<60").score(&dessert).is_none());
+        assert!(RecipeQuery::parse("<60").score(&recipe("Sans temps", &[])).is_none());
+    }
+}
@@ -1 +1,2 @@
-mod main_view;
\ No newline at end of file
+mod main_view;
+mod theme;
\ No newline at end of file
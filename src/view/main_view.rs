@@ -1,47 +1,106 @@
 use crate::controller::main_controller::Message::{
     DecrementedNbrPersonsOfRecipe, IncrementedNbrPersonsOfRecipe, ReturnButtonPressed,
 };
-use crate::controller::main_controller::{MainController, Message, RecipeSlot, View};
-use crate::model::recipe::{Recipe, EVENING, NOON};
-use crate::model::weekday::{FRIDAY, MONDAY, SATURDAY, SUNDAY, THURSDAY, TUESDAY, WEDNESDAY};
+use crate::controller::main_controller::{
+    MainController, ManualItemField, Message, RECIPE_SEARCH_INPUT, SettingField, SlotClipboard, View,
+};
+use crate::model::calendar_date::CalendarDate;
+use crate::model::dashboard::DashboardWarning;
+use crate::model::diet::DietaryRestriction;
+use crate::model::ingredient::{WHOLE_INGREDIENT, round_smartly};
+use crate::model::language::{Labelled, Language};
+use crate::model::person_count::PersonCount;
+use crate::model::price::format_price;
+use crate::model::recipe::Recipe;
+use crate::model::recipe_metadata::format_duration;
+use crate::model::nutrition::{NutritionEstimate, per_person_total};
+use crate::model::recipe_slot::{Day, Meal, RecipeSlot};
+use crate::model::recipe_stats::{MAX_RATING_STARS, MIN_RATING_STARS};
+use crate::model::theme::ThemeMode;
+use crate::service::search_service::RecipeSortKey;
 use iced::widget::{
-    Button, Column, Row, Space, TextInput, Toggler, button, column, container, horizontal_rule,
-    row, scrollable, text, text_input, toggler, vertical_rule,
+    Button, Column, Row, Space, TextInput, Toggler, button, checkbox, column, container, horizontal_rule,
+    pick_list, row, scrollable, text, text_input, toggler, vertical_rule,
 };
+use crate::view::theme::{empty_slot, planned_slot, veggie_slot};
 use iced::{Alignment, Element, Length};
 
+// Marks the slot or the recipe chosen with the keyboard
+const FOCUS_MARK: &str = "▸ ";
+
 impl MainController {
     pub fn list_all_recipes__as_clickable_buttons(&self) -> Element<Message> {
-        let only_veggie_recipes = self
-            .slots_filtering_veggie_recipes
-            .contains(&self.slot_currently_in_edition.unwrap());
+        let texts = self.settings.language.texts();
+        let mut all_buttons: Column<Message> = Column::new();
 
-        let filter = {
-            if self
-                .filters_on_recipes_slots
-                .contains_key(&self.slot_currently_in_edition.unwrap())
-            {
-                self.filters_on_recipes_slots[&self.slot_currently_in_edition.unwrap()].clone()
-            } else {
-                "".to_string()
-            }
-        };
+        for (position, recipe) in self.listed_recipe_names().into_iter().enumerate() {
+            let Some(recipe_content) = self.recipe_service.find_recipe_by_name(&recipe) else {
+                continue;
+            };
+            let diet_profile = self.catalogue_service.diet_profile(&recipe_content);
+            let out_of_season_ingredients =
+                self.out_of_season_ingredients(&recipe_content, self.slot_currently_in_edition.unwrap());
 
-        let all_recipes = self
-            .recipe_service
-            .list_recipes(filter, only_veggie_recipes);
-        let mut all_buttons: Column<Message> = Column::new();
+            let stats = self.recipe_service.recipe_stats_repository.get_stats(&recipe);
+            let mut recipe_details: Vec<String> = Vec::new();
+            if let Some(average_rating) = stats.average_rating() {
+                recipe_details.push(format!("★ {average_rating:.1}"));
+            }
+            recipe_details.push(recipe_content.metadata.summary(self.settings.language));
+            if let Some(diet_label) = diet_profile.diet_label(self.settings.language) {
+                recipe_details.push(diet_label.to_string());
+            }
+            let diet_tags = diet_profile.tags();
+            if !diet_tags.is_empty() {
+                let labels: Vec<&str> = diet_tags.iter().map(|tag| tag.label(self.settings.language)).collect();
+                recipe_details.push(format!("{}{}", texts.contains, labels.join(", ")));
+            }
+            recipe_details.retain(|detail| !detail.is_empty());
 
-        for recipe in all_recipes {
-            all_buttons = all_buttons.push(button(text(recipe.clone())).on_press(
-                Message::SelectedRecipe(self.slot_currently_in_edition.unwrap(), Some(recipe)),
-            ));
+            let recipe_label = match position == self.highlighted_recipe {
+                true => format!("{FOCUS_MARK}{recipe}"),
+                false => recipe.clone(),
+            };
+            let mut button_content = column![text(recipe_label)];
+            if !recipe_details.is_empty() {
+                button_content = button_content.push(text(recipe_details.join(" · ")).size(12));
+            }
+            if let Some(inconsistency) = diet_profile.veggie_inconsistency(recipe_content.is_veggie, self.settings.language) {
+                button_content = button_content.push(text(format!("⚠ {inconsistency}")).size(12));
+            }
+            if !out_of_season_ingredients.is_empty() {
+                button_content = button_content
+                    .push(text(format!("⚠ {}{}", texts.out_of_season, out_of_season_ingredients.join(", "))).size(12));
+            }
+            let conflicts = self.household_service.conflicts(
+                &diet_profile,
+                self.absent_members_of(self.slot_currently_in_edition.unwrap()),
+                self.settings.language,
+            );
+            if !conflicts.is_empty() {
+                button_content =
+                    button_content.push(text(format!("⚠ {}{}", texts.not_suitable_for, conflicts.join(", "))).size(12));
+            }
+            let favourite_button = button(text(if stats.is_favourite { "★" } else { "☆" }))
+                .on_press(Message::ToggledFavouriteRecipe(recipe.clone()));
+            all_buttons = all_buttons.push(
+                row![
+                    favourite_button,
+                    button(button_content).on_press(Message::SelectedRecipe(
+                        self.slot_currently_in_edition.unwrap(),
+                        Some(recipe)
+                    )),
+                ]
+                .spacing(5)
+                .align_y(Alignment::Center),
+            );
         }
         all_buttons = all_buttons.spacing(5);
         all_buttons.into()
     }
 
     pub fn view__recipe_selection(&self) -> Element<Message> {
+        let texts = self.settings.language.texts();
         let search_bar__content = {
             if self
                 .filters_on_recipes_slots
@@ -53,27 +112,60 @@ impl MainController {
             }
         };
         let search_bar: TextInput<Message> =
-            text_input("Filtrer par nom...", search_bar__content.as_str())
-                .on_input(Message::FilteredSlotRecipe);
+            text_input(texts.search_placeholder, search_bar__content.as_str())
+                .id(text_input::Id::new(RECIPE_SEARCH_INPUT))
+                .on_input(Message::FilteredSlotRecipe)
+                .on_submit(Message::ConfirmedHighlightedRecipe);
 
         let toggler_is_checked = self
             .slots_filtering_veggie_recipes
             .contains(&self.slot_currently_in_edition.unwrap());
         let veggie_toggler: Toggler<Message> = toggler(toggler_is_checked)
             .on_toggle(Message::FilteringVeggieRecipes)
-            .label("Seulement les recettes végétariennes");
+            .label(texts.only_veggies);
+
+        let favourite_toggler: Toggler<Message> = toggler(
+            self.slots_filtering_favourite_recipes
+                .contains(&self.slot_currently_in_edition.unwrap()),
+        )
+        .on_toggle(Message::FilteringFavouriteRecipes)
+        .label(texts.only_favourites);
+
+        let recipe_slot = self.slot_currently_in_edition.unwrap();
+        let seasonal_toggler: Toggler<Message> = toggler(self.slots_filtering_seasonal_recipes.contains(&recipe_slot))
+            .on_toggle(Message::FilteringSeasonalRecipes)
+            .label(format!("{} ({})", texts.in_season, self.date_of(recipe_slot.day).month_name(self.settings.language)));
+
+        let mut restrictions_row = Row::new().spacing(10).push(text(texts.household_diets));
+        for restriction in DietaryRestriction::ALL {
+            restrictions_row = restrictions_row.push(
+                checkbox(restriction.label(self.settings.language), self.household_restrictions.contains(&restriction))
+                    .on_toggle(move |is_restricted| {
+                        Message::ToggledHouseholdRestriction(restriction, is_restricted)
+                    }),
+            );
+        }
 
         scrollable(
             row![
-                button("Retour").on_press(ReturnButtonPressed),
+                button(texts.back).on_press(ReturnButtonPressed),
                 column![
                     row![
                         search_bar,
                         veggie_toggler,
+                        favourite_toggler,
+                        seasonal_toggler,
+                        pick_list(
+                            RecipeSortKey::ALL.map(|sort_key| Labelled { value: sort_key, label: sort_key.label(self.settings.language) }),
+                            Some(Labelled { value: self.recipe_sort_key, label: self.recipe_sort_key.label(self.settings.language) }),
+                            |sort_key| Message::SelectedRecipeSortKey(sort_key.value)
+                        ),
                         Space::with_width(Length::Fixed(10.0)),
                     ]
                     .spacing(10),
-                    button(" -- AUCUNE RECETTE --").on_press(Message::SelectedRecipe(
+                    text(texts.query_syntax_help).size(12),
+                    restrictions_row,
+                    button(texts.no_recipe).on_press(Message::SelectedRecipe(
                         self.slot_currently_in_edition.unwrap(),
                         None
                     )),
@@ -87,37 +179,101 @@ impl MainController {
     }
 
     pub fn generate_recipe_selector(&self, recipe_slot: RecipeSlot) -> Element<Message> {
+        let texts = self.settings.language.texts();
         let mut button_name = String::new();
         let mut selected_recipe: Option<Recipe> = None;
         if self.selected_recipes.contains_key(&recipe_slot) {
             selected_recipe = Some(self.selected_recipes[&recipe_slot].clone());
             button_name = self.selected_recipes[&recipe_slot].name.clone()
         } else {
-            button_name = "Sélectionnez une recette".to_string()
+            button_name = texts.select_recipe.to_string()
         }
 
+        if recipe_slot == self.focused_slot {
+            button_name = format!("{FOCUS_MARK}{button_name}");
+        }
         let select_recipe_button: Button<Message> = Button::new(text(button_name))
             .on_press(Message::SelectedRecipeSlot(recipe_slot))
+            .style(match &selected_recipe {
+                Some(recipe) if recipe.is_veggie => button::success,
+                Some(_) => button::primary,
+                None => button::secondary,
+            })
             .width(Length::Fill);
 
+        let mut attendance = Column::new().spacing(2);
+        if self.household_service.has_members() {
+            attendance = attendance.push(text(texts.who_eats).size(12));
+            for member in self.household_service.household_repository.list_all_members() {
+                let member_name = member.name.clone();
+                attendance = attendance.push(
+                    checkbox(member.name.clone(), !self.absent_members_of(recipe_slot).contains(&member.name))
+                        .size(12)
+                        .text_size(12)
+                        .on_toggle(move |is_present| {
+                            Message::ToggledMemberAttendance(recipe_slot, member_name.clone(), is_present)
+                        }),
+                );
+            }
+        }
+
         if let Some(selected_recipe) = selected_recipe {
+            let diet_profile = self.catalogue_service.diet_profile(&selected_recipe);
+            for conflict in self.household_service.conflicts(&diet_profile, self.absent_members_of(recipe_slot), self.settings.language) {
+                attendance = attendance.push(text(format!("⚠ {conflict}")).size(12));
+            }
+            let out_of_season_ingredients = self.out_of_season_ingredients(&selected_recipe, recipe_slot);
+            if !out_of_season_ingredients.is_empty() {
+                attendance = attendance
+                    .push(text(format!("⚠ {}{}", texts.out_of_season, out_of_season_ingredients.join(", "))).size(12));
+            }
+            let nutrition_estimate = self.catalogue_service.nutrition_estimate(&selected_recipe);
+            let mut nutrition = column![
+                text(format!("≈ {:.0} kcal {}", nutrition_estimate.per_portion().kcal, texts.per_portion)).size(12)
+            ];
+            if !nutrition_estimate.is_complete() {
+                nutrition = nutrition.push(text(format!("⚠ {}", nutrition_estimate.missing_summary(self.settings.language))).size(10));
+            }
+            let cost_estimate = self.catalogue_service.recipe_cost_estimate(&selected_recipe);
+            let warning = if cost_estimate.is_complete() { "" } else { " ⚠" };
+            nutrition = nutrition.push(
+                text(format!(
+                    "≈ {} · {} {}{warning}",
+                    format_price(cost_estimate.total(), self.settings.language),
+                    format_price(cost_estimate.per_portion(selected_recipe.configured_nbr_persons), self.settings.language),
+                    texts.per_portion
+                ))
+                .size(12),
+            );
+
+            let nbr_persons_input = match self.nbr_persons_inputs.get(&recipe_slot) {
+                Some(nbr_persons_input) => nbr_persons_input.clone(),
+                None => selected_recipe.configured_nbr_persons.label(self.settings.language),
+            };
+            let mut nbr_persons_column = column![
+                text(texts.persons_input),
+                text_input("", nbr_persons_input.as_str())
+                    .on_input(move |input| Message::EditedNbrPersonsOfRecipe(recipe_slot, input))
+                    .width(Length::Fixed(45.)),
+            ];
+            if let Err(error) = PersonCount::parse(&nbr_persons_input, self.settings.language) {
+                nbr_persons_column = nbr_persons_column.push(text(error).size(10));
+            }
+
             column![
                 select_recipe_button.height(Length::Fixed(75.)),
                 row![
-                    container(column![text(format!(
-                        "Pour: {}",
-                        selected_recipe.configured_nbr_persons.to_string()
-                    )),])
+                    container(nbr_persons_column)
                     .height(Length::Fill)
                     .align_y(Alignment::Center),
                     container(
                         column![
                             button("+")
-                                .on_press(IncrementedNbrPersonsOfRecipe(recipe_slot, 1))
+                                .on_press(IncrementedNbrPersonsOfRecipe(recipe_slot, self.nbr_persons_step()))
                                 .width(Length::Fill)
                                 .clip(false),
                             button("-")
-                                .on_press(DecrementedNbrPersonsOfRecipe(recipe_slot, 1))
+                                .on_press(DecrementedNbrPersonsOfRecipe(recipe_slot, self.nbr_persons_step()))
                                 .width(Length::Fill)
                                 .clip(false),
                         ]
@@ -126,111 +282,490 @@ impl MainController {
                     .height(Length::Fill)
                     .align_y(Alignment::Center),
                 ]
-                .spacing(10)
+                .spacing(10),
+                attendance,
+                nutrition,
+                row![
+                    button(text(format!("{} ({})", texts.default_persons, selected_recipe.nbr_persons)).size(12))
+                        .on_press(Message::ResetNbrPersonsOfRecipe(recipe_slot)),
+                    button(text(texts.rate).size(12))
+                        .on_press(Message::RateRecipeButtonPressed(selected_recipe.name.clone())),
+                ]
+                .spacing(5),
+                self.generate_clipboard_buttons(recipe_slot),
             ]
             .spacing(20)
             .into()
         } else {
-            select_recipe_button.into()
+            column![select_recipe_button, attendance, self.generate_clipboard_buttons(recipe_slot)]
+                .spacing(10)
+                .into()
         }
     }
 
-    pub fn generate_recipe_slot(
-        &self,
-        week_day: String,
-        recipe_slot: RecipeSlot,
-    ) -> Column<Message> {
-        let mut spacing_height = 2;
-        if self.selected_recipes.contains_key(&recipe_slot) {
-            spacing_height = 1;
+    pub fn generate_clipboard_buttons(&self, recipe_slot: RecipeSlot) -> Row<'_, Message> {
+        let texts = self.settings.language.texts();
+        let is_planned = self.selected_recipes.contains_key(&recipe_slot);
+        let mut clipboard_buttons = Row::new().spacing(5);
+        match &self.clipboard {
+            Some(SlotClipboard::Cut(cut_slot, _)) if *cut_slot == recipe_slot => {
+                clipboard_buttons = clipboard_buttons
+                    .push(button(text(texts.cancel).size(12)).on_press(Message::CancelledClipboard));
+            }
+            Some(clipboard) => {
+                let label = match (clipboard, is_planned) {
+                    (SlotClipboard::Cut(..), true) => texts.swap,
+                    (_, true) => texts.replace,
+                    (_, false) => texts.paste,
+                };
+                clipboard_buttons =
+                    clipboard_buttons.push(button(text(label).size(12)).on_press(Message::PastedRecipe(recipe_slot)));
+            }
+            None => {}
         }
+        if is_planned && !matches!(&self.clipboard, Some(SlotClipboard::Cut(cut_slot, _)) if *cut_slot == recipe_slot) {
+            clipboard_buttons = clipboard_buttons
+                .push(button(text(texts.cut).size(12)).on_press(Message::CutRecipe(recipe_slot)))
+                .push(button(text(texts.copy).size(12)).on_press(Message::CopiedRecipe(recipe_slot)));
+        }
+        clipboard_buttons
+    }
+
+    pub fn generate_recipe_slot(&self, recipe_slot: RecipeSlot) -> Column<Message> {
+        let slot_style = match self.selected_recipes.get(&recipe_slot) {
+            Some(recipe) if recipe.is_veggie => veggie_slot,
+            Some(_) => planned_slot,
+            None => empty_slot,
+        };
         column![
-            text(week_day),
+            text(format!("{} {}", recipe_slot.day.name(self.settings.language), self.date_of(recipe_slot.day).short())),
             Space::with_height(Length::Fixed(5.)),
-            self.generate_recipe_selector(recipe_slot),
+            container(self.generate_recipe_selector(recipe_slot)).padding(4).style(slot_style),
         ]
         .align_x(Alignment::Center)
     }
 
-    pub fn generate_recipe_slots_row(
-        &self,
-        row_name: String,
-        week_days: [&str; 7],
-        recipe_slots: [RecipeSlot; 7],
-    ) -> Row<Message> {
+    pub fn generate_recipe_slots_row(&self, meal: Meal) -> Row<Message> {
         let mut recipe_slots_row = Row::new().spacing(12);
         recipe_slots_row = recipe_slots_row.push(
-            column![text(row_name),]
+            column![
+                text(meal.name(self.settings.language)),
+                text(self.settings.meal_time(meal)).size(12),
+            ]
                 .width(Length::Fixed(50.))
                 .align_x(Alignment::Center),
         );
 
-        recipe_slots_row = recipe_slots_row.push(vertical_rule(2));
+        for recipe_slot in meal.slots() {
+            recipe_slots_row = recipe_slots_row.push(vertical_rule(2));
+            recipe_slots_row = recipe_slots_row.push(self.generate_recipe_slot(recipe_slot));
+        }
+        recipe_slots_row.push(Space::with_width(Length::Fixed(0.)))
+    }
 
-        for i in 0..7 {
-            let week_day = week_days[i].to_string();
-            let recipe_slot = recipe_slots[i].clone();
-            recipe_slots_row =
-                recipe_slots_row.push(self.generate_recipe_slot(week_day, recipe_slot));
-            match i {
-                6 => recipe_slots_row = recipe_slots_row.push(Space::with_width(Length::Fixed(0.))),
-                _ => recipe_slots_row = recipe_slots_row.push(vertical_rule(2)),
+    pub fn generate_daily_nutrition_row(&self) -> Row<'_, Message> {
+        let texts = self.settings.language.texts();
+        let mut daily_nutrition_row = Row::new().spacing(12);
+        daily_nutrition_row = daily_nutrition_row.push(
+            column![text(texts.per_day).size(12)]
+                .width(Length::Fixed(50.))
+                .align_x(Alignment::Center),
+        );
+
+        for day in Day::ALL {
+            let nutrition_estimates: Vec<NutritionEstimate> = Meal::ALL
+                .into_iter()
+                .filter_map(|meal| self.selected_recipes.get(&day.slot(meal)))
+                .map(|recipe| self.catalogue_service.nutrition_estimate(recipe))
+                .collect();
+            let daily_nutrition = match nutrition_estimates.is_empty() {
+                true => String::new(),
+                false => {
+                    let (day_total, is_complete) = per_person_total(&nutrition_estimates);
+                    let warning = if is_complete { "" } else { " ⚠" };
+                    format!("≈ {:.0} kcal {}{warning}\n{}", day_total.kcal, texts.per_person, day_total.summary())
+                }
             };
+            daily_nutrition_row = daily_nutrition_row.push(vertical_rule(2));
+            daily_nutrition_row = daily_nutrition_row.push(
+                container(text(daily_nutrition).size(10))
+                    .width(Length::Fill)
+                    .align_x(Alignment::Center),
+            );
         }
-        recipe_slots_row
+        daily_nutrition_row.push(Space::with_width(Length::Fixed(0.)))
     }
 
-    pub fn view__main(&self) -> Element<Message> {
-        let week_days: [&str; 7] = [
-            MONDAY, TUESDAY, WEDNESDAY, THURSDAY, FRIDAY, SATURDAY, SUNDAY,
-        ];
-        let noon_slots: [RecipeSlot; 7] = [
-            RecipeSlot::MondayNoon,
-            RecipeSlot::TuesdayNoon,
-            RecipeSlot::WednesdayNoon,
-            RecipeSlot::ThursdayNoon,
-            RecipeSlot::FridayNoon,
-            RecipeSlot::SaturdayNoon,
-            RecipeSlot::SundayNoon,
-        ];
-
-        let evening_slots: [RecipeSlot; 7] = [
-            RecipeSlot::MondayEvening,
-            RecipeSlot::TuesdayEvening,
-            RecipeSlot::WednesdayEvening,
-            RecipeSlot::ThursdayEvening,
-            RecipeSlot::FridayEvening,
-            RecipeSlot::SaturdayEvening,
-            RecipeSlot::SundayEvening,
-        ];
+    pub fn generate_week_summary(&self) -> Element<'_, Message> {
+        let texts = self.settings.language.texts();
+        let all_ingredients = self.week_shopping_list();
+        let week_cost = self.catalogue_service.cost_estimate(&all_ingredients);
+        let mut week_cost_text = format!("{}{}", texts.week_shopping, format_price(week_cost.total(), self.settings.language));
+        if !week_cost.is_complete() {
+            week_cost_text.push_str(&format!(
+                " (⚠ {}{})",
+                texts.without_price,
+                week_cost.ingredients_without_price.join(", ")
+            ));
+        }
+        let mut week_summary = column![text(week_cost_text).size(12)].align_x(Alignment::Center);
+        for (leftover, recipe_names) in self.leftover_suggestions.iter() {
+            let leftover_text = match recipe_names.is_empty() {
+                true => format!("{}{leftover}", texts.leftover),
+                false => format!("{}{leftover} → {}", texts.leftover, recipe_names.join(", ")),
+            };
+            week_summary = week_summary.push(text(leftover_text).size(10));
+        }
+        container(week_summary)
+            .width(Length::Fill)
+            .align_x(Alignment::Center)
+            .into()
+    }
 
-        let mut main_view = Column::new();
-        let noon_row = self.generate_recipe_slots_row(String::from(NOON), week_days, noon_slots);
-        let evening_row =
-            self.generate_recipe_slots_row(String::from(EVENING), week_days, evening_slots);
+    pub fn generate_week_dashboard(&self) -> Element<'_, Message> {
+        let texts = self.settings.language.texts();
+        let language = self.settings.language;
+        let dashboard = self.week_dashboard();
+        let nbr_slots = Day::ALL.len() * Meal::ALL.len();
+        let mut kitchen_time_text = format!("≈ {} {}", format_duration(dashboard.kitchen_time), texts.kitchen_time);
+        if dashboard.nbr_meals_without_time > 0 {
+            kitchen_time_text.push_str(&format!(" ({} {})", dashboard.nbr_meals_without_time, texts.meals_without_time));
+        }
+        let figures = row![
+            text(format!("{} / {nbr_slots} {}", dashboard.nbr_planned_meals, texts.planned_meals)),
+            text(format!(
+                "{} {} · {} {}",
+                dashboard.nbr_veggie_meals,
+                texts.veggie_meals,
+                dashboard.nbr_other_meals(),
+                texts.other_meals
+            )),
+            text(format!("{} {}", dashboard.total_portions.label(self.settings.language), texts.portions)),
+            text(format!("{} {}", dashboard.nbr_shopping_items, texts.shopping_items)),
+            text(kitchen_time_text),
+        ]
+        .spacing(25);
 
-        main_view = main_view.push(noon_row);
-        main_view = main_view.push(horizontal_rule(2));
-        main_view = main_view.push(evening_row);
+        let mut dashboard_column = column![figures].spacing(2).align_x(Alignment::Center);
+        for warning in dashboard.warnings {
+            let warning_text = match warning {
+                DashboardWarning::EmptySlots(empty_slots) => {
+                    let slot_names: Vec<String> = empty_slots
+                        .iter()
+                        .map(|slot| format!("{} {}", slot.day.name(language), slot.meal.name(language).to_lowercase()))
+                        .collect();
+                    format!("{}{}", texts.empty_slots, slot_names.join(", "))
+                }
+                DashboardWarning::RepeatedRecipe { recipe_name, nbr_times } => {
+                    format!("« {recipe_name} » ×{nbr_times} {}", texts.in_the_week)
+                }
+                DashboardWarning::MissingRecipe { recipe_name, recipe_slot } => format!(
+                    "« {recipe_name} » ({} {}) {}",
+                    recipe_slot.day.name(language),
+                    recipe_slot.meal.name(language).to_lowercase(),
+                    texts.missing_recipe
+                ),
+            };
+            dashboard_column = dashboard_column.push(text(format!("⚠ {warning_text}")).size(10));
+        }
+        container(dashboard_column)
+            .width(Length::Fill)
+            .align_x(Alignment::Center)
+            .into()
+    }
+
+    fn week_start_summary(&self) -> String {
+        match CalendarDate::parse(&self.week_start_input, self.settings.language) {
+            Ok(date) if date == self.week_start => String::new(),
+            Ok(_) => format!("{}{}", self.settings.language.texts().monday, self.week_start),
+            Err(error) => error,
+        }
+    }
+
+    pub fn view__main(&self) -> Element<Message> {
+        let texts = self.settings.language.texts();
+        let mut main_view = Column::new();
+        for meal in Meal::ALL {
+            main_view = main_view.push(self.generate_recipe_slots_row(meal));
+            main_view = main_view.push(horizontal_rule(2));
+        }
+        main_view = main_view.push(self.generate_daily_nutrition_row());
         main_view = main_view.push(horizontal_rule(2));
         main_view = main_view.push(Space::with_height(Length::Fixed(10.0)));
+        main_view = main_view.push(self.generate_week_dashboard());
+        let warnings = self
+            .recipe_loading_errors
+            .iter()
+            .chain(self.recipe_stats_error.iter())
+            .chain(self.settings_warning.iter())
+            .chain(self.settings_error.iter());
+        for warning in warnings {
+            main_view = main_view.push(
+                container(text(format!("⚠ {warning}")).size(10))
+                    .width(Length::Fill)
+                    .align_x(Alignment::Center),
+            );
+        }
+        main_view = main_view.push(self.generate_week_summary());
         main_view = main_view.push(
             row![
                 Space::with_width(Length::FillPortion(1)),
-                button("Générer menu").on_press(Message::GenerateRecipeDocument),
-                button("Importer").on_press(Message::ImportExcelFile),
+                button(texts.undo).on_press_maybe(self.plan_history.can_undo().then_some(Message::Undo)),
+                button(texts.redo).on_press_maybe(self.plan_history.can_redo().then_some(Message::Redo)),
+                button(texts.generate_menu).on_press(Message::GenerateRecipeDocument),
+                button(texts.import_menu).on_press(Message::ImportExcelFile),
+                button(texts.import_recipe).on_press(Message::ImportRecipeFile),
+                button(texts.shopping_list).on_press(Message::OpenedShoppingList),
+                text(texts.week_of),
+                text_input(texts.date_placeholder, &self.week_start_input)
+                    .on_input(Message::EditedWeekStart)
+                    .width(Length::Fixed(100.)),
+                text(self.week_start_summary()).size(12),
+                toggler(self.allow_half_portions)
+                    .on_toggle(Message::ToggledHalfPortions)
+                    .label(texts.half_portions)
+                    .width(Length::Shrink),
+                pick_list(Language::ALL, Some(self.settings.language), Message::SelectedLanguage),
+                button(texts.settings).on_press(Message::OpenedSettings),
                 Space::with_width(Length::FillPortion(1))
             ]
             .spacing(10),
         );
+        main_view = main_view.push(
+            container(text(texts.keyboard_shortcuts_help).size(10))
+                .width(Length::Fill)
+                .align_x(Alignment::Center),
+        );
+        if let Some(clipboard) = &self.clipboard {
+            let clipboard_summary = match clipboard {
+                SlotClipboard::Cut(cut_slot, _) => format!(
+                    "{} {} {}",
+                    cut_slot.day.name(self.settings.language),
+                    cut_slot.meal.name(self.settings.language).to_lowercase(),
+                    texts.cut_pending
+                ),
+                SlotClipboard::Copied(recipe) => format!("« {} » {}", recipe.name, texts.copied_pending),
+            };
+            main_view = main_view.push(
+                row![
+                    text(clipboard_summary).size(12),
+                    button(text(texts.done).size(12)).on_press(Message::CancelledClipboard),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+        }
         main_view = main_view.push(Space::with_height(Length::Fixed(10.0)));
         main_view.into()
     }
 
+    pub fn view_recipe_rating(&self) -> Element<'_, Message> {
+        let texts = self.settings.language.texts();
+        let recipe_name = self.recipe_in_rating.clone().unwrap_or_default();
+        let mut stars_row = Row::new().spacing(5);
+        for stars in MIN_RATING_STARS..=MAX_RATING_STARS {
+            let star = if stars <= self.rating_stars { "★" } else { "☆" };
+            stars_row = stars_row.push(button(text(star).size(24)).on_press(Message::SelectedRatingStars(stars)));
+        }
+
+        let mut previous_ratings = Column::new().spacing(5);
+        let stats = self.recipe_service.recipe_stats_repository.get_stats(&recipe_name);
+        for rating in stats.ratings.iter().rev() {
+            let stars = "★".repeat(rating.stars as usize);
+            match rating.note.is_empty() {
+                true => previous_ratings = previous_ratings.push(text(stars)),
+                false => previous_ratings = previous_ratings.push(text(format!("{stars} : {}", rating.note))),
+            }
+        }
+
+        scrollable(
+            row![
+                button(texts.back).on_press(ReturnButtonPressed),
+                column![
+                    text(format!("{}« {recipe_name} »", texts.rate_recipe)).size(20),
+                    stars_row,
+                    text_input(texts.rating_placeholder, self.rating_note.as_str())
+                        .on_input(Message::EditedRatingNote)
+                        .on_submit(Message::SavedRecipeRating),
+                    button(texts.save).on_press(Message::SavedRecipeRating),
+                    horizontal_rule(2),
+                    text(texts.previous_ratings),
+                    previous_ratings,
+                ]
+                .spacing(10),
+            ]
+            .spacing(10),
+        )
+        .into()
+    }
+
+    pub fn view_settings(&self) -> Element<'_, Message> {
+        let texts = self.settings.language.texts();
+        let settings = &self.settings_in_edition;
+        let setting_input = |label: &str, placeholder: &str, value: &str, setting_field: SettingField| {
+            row![
+                text(label.to_string()).width(Length::Fixed(260.)),
+                text_input(placeholder, value)
+                    .on_input(move |input| Message::EditedSetting(setting_field, input))
+                    .on_submit(Message::SavedSettings)
+                    .width(Length::Fixed(300.)),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center)
+        };
+
+        let mut settings_column = column![
+            text(texts.settings).size(20),
+            setting_input(texts.recipes_directory, "", &settings.recipes_directory, SettingField::RecipesDirectory),
+            setting_input(texts.output_file, "", &settings.output_file, SettingField::OutputFile),
+            setting_input(texts.header_colour, "", &settings.header_colour, SettingField::HeaderColour),
+            setting_input(
+                texts.default_nbr_persons,
+                texts.recipe_nbr_persons,
+                &self.default_nbr_persons_input,
+                SettingField::DefaultNbrPersons,
+            ),
+            row![
+                text(texts.language).width(Length::Fixed(260.)),
+                pick_list(Language::ALL, Some(settings.language), Message::SelectedSettingsLanguage),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+            row![
+                text(texts.theme).width(Length::Fixed(260.)),
+                pick_list(
+                    ThemeMode::ALL.map(|theme_mode| Labelled { value: theme_mode, label: theme_mode.label(self.settings.language) }),
+                    Some(Labelled { value: settings.theme, label: settings.theme.label(self.settings.language) }),
+                    |theme_mode| Message::SelectedTheme(theme_mode.value),
+                ),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+            setting_input(texts.accent_colour, texts.theme_accent, &settings.accent_colour, SettingField::AccentColour),
+            text(texts.meal_times),
+            setting_input(Meal::Noon.name(self.settings.language), "12:00", &settings.noon_time, SettingField::NoonTime),
+            setting_input(
+                Meal::Evening.name(self.settings.language),
+                "19:30",
+                &settings.evening_time,
+                SettingField::EveningTime,
+            ),
+        ]
+        .spacing(10);
+        for settings_error in self.settings_warning.iter().chain(self.settings_error.iter()) {
+            settings_column = settings_column.push(text(format!("⚠ {settings_error}")));
+        }
+        settings_column = settings_column.push(
+            row![
+                button(texts.save).on_press(Message::SavedSettings),
+                button(texts.restore_defaults).on_press(Message::RestoredDefaultSettings),
+            ]
+            .spacing(10),
+        );
+        settings_column = settings_column.push(
+            text(format!("{}{}", texts.settings_file, self.settings_repository.settings_file().display())).size(12),
+        );
+
+        scrollable(row![button(texts.back).on_press(ReturnButtonPressed), settings_column].spacing(10)).into()
+    }
+
+    pub fn view_shopping_list(&self) -> Element<'_, Message> {
+        let texts = self.settings.language.texts();
+        let language = self.settings.language;
+        let shopping_list = self.shopping_list();
+        let nbr_ticked_items = shopping_list.iter().filter(|line| line.is_ticked).count();
+
+        let mut shopping_list_column = column![
+            text(texts.shopping_list).size(20),
+            text(format!(
+                "{} {} · {} {}",
+                shopping_list.len() - nbr_ticked_items,
+                texts.items_to_buy,
+                nbr_ticked_items,
+                texts.already_at_home
+            ))
+            .size(12),
+        ]
+        .spacing(8);
+        if shopping_list.is_empty() {
+            shopping_list_column = shopping_list_column.push(text(texts.empty_shopping_list));
+        }
+        for line in shopping_list {
+            let key = line.key();
+            let quantity_input = match self.shopping_quantity_inputs.get(&key) {
+                Some(quantity_input) => quantity_input.clone(),
+                None => round_smartly(line.ingredient.quantity).to_string(),
+            };
+            let unit = match line.ingredient.unit.as_str() {
+                WHOLE_INGREDIENT => "",
+                unit => unit,
+            };
+            let mut sources: Vec<String> = line
+                .sources
+                .iter()
+                .map(|source| source.breakdown(&line.ingredient.unit, language))
+                .collect();
+            if line.is_edited {
+                sources.push(texts.edited_quantity.to_string());
+            }
+
+            let toggled_key = key.clone();
+            let edited_key = key.clone();
+            let mut line_row = row![
+                checkbox(line.ingredient.name.clone(), line.is_ticked)
+                    .on_toggle(move |is_ticked| Message::ToggledShoppingItem(toggled_key.clone(), is_ticked))
+                    .width(Length::Fixed(220.)),
+                text_input("", &quantity_input)
+                    .on_input(move |input| Message::EditedShoppingItemQuantity(edited_key.clone(), input))
+                    .width(Length::Fixed(70.)),
+                text(unit.to_string()).width(Length::Fixed(60.)),
+                text(sources.join(" · ")).size(12),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center);
+            if line.is_manual_only() {
+                line_row = line_row.push(button(text(texts.remove).size(12)).on_press(Message::RemovedManualItem(key)));
+            }
+            shopping_list_column = shopping_list_column.push(line_row);
+        }
+
+        shopping_list_column = shopping_list_column.push(horizontal_rule(2));
+        shopping_list_column = shopping_list_column.push(
+            row![
+                text_input(texts.item_placeholder, &self.manual_item_name)
+                    .on_input(|input| Message::EditedManualItem(ManualItemField::Name, input))
+                    .on_submit(Message::AddedManualItem)
+                    .width(Length::Fixed(220.)),
+                text_input(texts.quantity, &self.manual_item_quantity)
+                    .on_input(|input| Message::EditedManualItem(ManualItemField::Quantity, input))
+                    .on_submit(Message::AddedManualItem)
+                    .width(Length::Fixed(70.)),
+                text_input(texts.unit_placeholder, &self.manual_item_unit)
+                    .on_input(|input| Message::EditedManualItem(ManualItemField::Unit, input))
+                    .on_submit(Message::AddedManualItem)
+                    .width(Length::Fixed(60.)),
+                button(texts.add_item).on_press(Message::AddedManualItem),
+            ]
+            .spacing(10),
+        );
+        if let Some(manual_item_error) = &self.manual_item_error {
+            shopping_list_column = shopping_list_column.push(text(format!("⚠ {manual_item_error}")).size(12));
+        }
+        shopping_list_column = shopping_list_column.push(
+            button(texts.reset_shopping_list)
+                .on_press_maybe((!self.shopping_list_edits.is_empty()).then_some(Message::ResetShoppingList)),
+        );
+
+        scrollable(row![button(texts.back).on_press(ReturnButtonPressed), shopping_list_column].spacing(10)).into()
+    }
+
     pub fn view(&self) -> Element<Message> {
         match self.current_view {
             View::Main => self.view__main(),
             View::RecipeSelection => self.view__recipe_selection(),
+            View::RecipeRating => self.view_recipe_rating(),
+            View::Settings => self.view_settings(),
+            View::ShoppingList => self.view_shopping_list(),
         }
     }
 }
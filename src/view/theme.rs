@@ -0,0 +1,81 @@
+use crate::controller::main_controller::{MainController, View};
+use crate::model::settings::Settings;
+use crate::model::theme::ThemeMode;
+use iced::widget::container;
+use iced::{Border, Color, Theme};
+
+const APPLICATION_THEME_NAME: &str = "Menus Manager";
+const SLOT_BORDER_RADIUS: f32 = 6.0;
+
+impl MainController {
+    /// Theme of the settings being edited while in the settings view, to preview them.
+    pub fn theme(&self) -> Theme {
+        match self.current_view {
+            View::Settings => application_theme(&self.settings_in_edition),
+            _ => application_theme(&self.settings),
+        }
+    }
+}
+
+fn application_theme(settings: &Settings) -> Theme {
+    let base_theme = match settings.theme {
+        // Detected once by iced from the mode of the desktop
+        ThemeMode::System => Theme::default(),
+        ThemeMode::Light => Theme::Light,
+        ThemeMode::Dark => Theme::Dark,
+    };
+    match settings.accent_colour_rgb() {
+        Some(accent_colour) => {
+            let mut palette = base_theme.palette();
+            palette.primary = colour_of(accent_colour);
+            Theme::custom(APPLICATION_THEME_NAME.to_string(), palette)
+        }
+        None => base_theme,
+    }
+}
+
+fn colour_of(rgb: u32) -> Color {
+    Color::from_rgb8((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+}
+
+/// Slot planned with a veggie recipe, framed in green.
+pub fn veggie_slot(theme: &Theme) -> container::Style {
+    let palette = theme.extended_palette();
+    container::Style {
+        background: Some(palette.success.weak.color.scale_alpha(0.15).into()),
+        border: Border {
+            color: palette.success.base.color,
+            width: 2.0,
+            radius: SLOT_BORDER_RADIUS.into(),
+        },
+        ..container::Style::default()
+    }
+}
+
+/// Slot planned with any other recipe.
+pub fn planned_slot(theme: &Theme) -> container::Style {
+    let palette = theme.extended_palette();
+    container::Style {
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: SLOT_BORDER_RADIUS.into(),
+        },
+        ..container::Style::default()
+    }
+}
+
+/// Slot with no recipe yet, greyed out.
+pub fn empty_slot(theme: &Theme) -> container::Style {
+    let palette = theme.extended_palette();
+    container::Style {
+        background: Some(palette.background.weak.color.into()),
+        text_color: Some(palette.background.weak.text),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: SLOT_BORDER_RADIUS.into(),
+        },
+        ..container::Style::default()
+    }
+}